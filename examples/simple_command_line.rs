@@ -1,45 +1,54 @@
 use my_parser_project::parser::{
-    combinator::{Concat, Map, SubResult, Substitute},
-    str_parser::{char, string, trimer},
+    combinator::ConcatUncheck,
+    str_parser::{char, keyword_table, trimer, KeywordOptions},
 };
 use std::io::stdin;
+
+// 命令
+#[derive(Clone)]
+enum Command {
+    // say <msg> : msgをユーザーに投げる
+    Say,
+    // exit : プログラムを終了させる
+    Exit,
+    // ping ; pong!とユーザーに投げる
+    Ping,
+}
+
 fn main() {
     // 標準入力を受け付ける
     let stdin = stdin();
 
+    // 命令の一覧
+    let commands = [
+        ("say", Command::Say),
+        ("exit", Command::Exit),
+        ("ping", Command::Ping),
+    ];
+    // パーサーは一度だけ作成し、使い回す
+    let command = keyword_table(&commands, KeywordOptions::default());
+
     loop {
-        let mut input = String::new();
-        _ = stdin.read_line(&mut input);
+        let mut line = String::new();
+        _ = stdin.read_line(&mut line);
 
         // 入力から命令を取り出す
-        let (input, opcode) = trimer.cat_b(
-            // say <msg> : msgをユーザーに投げる
-            string("say".to_string())
-                // exit : プログラムを終了させる
-                .sub(string("exit".to_string()))
-                // ping ; pong!とユーザーに投げる
-                .sub(string("ping".to_string()))
-                .map(|r| match r {
-                    Ok(SubResult::A(SubResult::A(_))) => 1,
-                    Ok(SubResult::A(SubResult::B(_))) => 2,
-                    Ok(SubResult::B(_)) => 3,
-                    Err(_) => 0,
-                }),
-        )(&input);
+        let (input, command) = trimer.cat_b_uncheck(command.clone())(&line);
 
-        match opcode {
-            1 => {
+        match command {
+            Ok(Command::Say) => {
                 let (msg, _) = char(' ')(input);
                 println!("{}", msg);
             }
-            2 => {
+            Ok(Command::Exit) => {
                 println!("bye");
                 break;
             }
-            3 => {
+            Ok(Command::Ping) => {
                 println!("pong!")
             }
-            _ => println!(""),
+            // 何が期待されていたかを、入力の該当箇所と一緒に表示する
+            Err(e) => print!("{}", e.report(&line)),
         }
     }
 }
@@ -1,25 +1,25 @@
-// use std::io::stdin;
+use std::io::stdin;
 
-// use my_parser_project::parser::numerical_expression;
+use my_parser_project::parser::numerical_expression;
 
 fn main() {
-    //     for line in stdin().lines() {
-    //         match line {
-    //             Ok(base) => match numerical_expression::parser(&base) {
-    //                 Ok((i, e)) => match e.calc() {
-    //                     Ok(ans) => {
-    //                         let i = i.trim_start();
-    //                         if i.len() > 0 {
-    //                             println!("={} ({})", ans, i)
-    //                         } else {
-    //                             println!("={}", ans)
-    //                         }
-    //                     }
-    //                     Err(e) => eprintln!("{}", e),
-    //                 },
-    //                 Err(e) => eprintln!("{}", e),
-    //             },
-    //             Err(e) => eprintln!("{}", e),
-    //         }
-    //     }
+    for line in stdin().lines() {
+        match line {
+            Ok(base) => match numerical_expression::parser(&base) {
+                (i, Ok(e)) => match e.calc() {
+                    Ok(ans) => {
+                        let i = i.trim_start();
+                        if !i.is_empty() {
+                            println!("={} ({})", ans, i)
+                        } else {
+                            println!("={}", ans)
+                        }
+                    }
+                    Err(e) => eprintln!("{}", e),
+                },
+                (_, Err(e)) => eprint!("{}", e.report(&base)),
+            },
+            Err(e) => eprintln!("{}", e),
+        }
+    }
 }
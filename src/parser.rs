@@ -6,13 +6,21 @@
 //! # パーサーの型について
 //! 基本的なパーサーの構造は、 [Parser] トレイトに従います。  
 //! [combinator]モジュールで、パーサーを組み合わせて動作するパーサーを作成できます。  
+pub mod bytes_parser;
 pub mod combinator;
+pub mod lexer;
+pub mod numerical_expression;
 pub mod str_parser;
+pub mod token_parser;
 
 // エラー
 mod error;
 pub use error::*;
 
+// 入力
+mod input;
+pub use input::*;
+
 /// パーサー関数をトレイトオブジェクト化します。
 ///
 /// 引数に指定されたスライスの先頭から、指定された条件で解析をし、一致した場合は結果を返却します。
@@ -0,0 +1,57 @@
+use super::*;
+
+/// 1バイトでパースする
+///
+/// 引数に入れたバイトを条件に解析する [Parser][super::Parser] を**作成**します。
+///
+/// # パーサーの仕様について
+/// - 先頭のみ解析を行います。
+/// ## 成功時
+/// - 条件のバイトが解析できたら、そのバイトが結果として返されます。
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseByteError][super::ErrorKind::ParseByteError] になります。
+///   - [span][super::error::Error::span]は、見つかったバイトを指します。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::bytes_parser::byte;
+///
+/// let input: &[u8] = b"\x7fELF";
+/// let (rest, result) = byte(0x7f)(input);
+/// assert_eq!(result, Ok(0x7f));
+/// assert_eq!(rest, b"ELF");
+/// ```
+pub fn byte<'a>(b: u8) -> impl Parser<&'a [u8], Result<u8, Error>> {
+    let label = format!("b'{}'", b.escape_ascii());
+    token_parser::satisfy_or(
+        move |x| x == b,
+        move |span| Error::new(ErrorKind::ParseByteError, span).with_expected(&label),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 正常系
+    #[test]
+    fn success_test() {
+        let base: &[u8] = &[0x00, 0xff];
+        let (rest, result) = byte(0x00)(base);
+        assert_eq!(result, Ok(0x00));
+        assert_eq!(rest, &[0xff]);
+    }
+
+    // 異常系: バイトが異なる
+    #[test]
+    fn failure_test() {
+        let base: &[u8] = b"ab";
+        let (rest, result) = byte(b'b')(base);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::ParseByteError);
+        assert_eq!(err.span().range(&base), 0..1);
+        assert_eq!(err.expected(), ["b'b'"]);
+        assert_eq!(rest, b"ab");
+    }
+}
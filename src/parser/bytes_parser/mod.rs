@@ -0,0 +1,24 @@
+//! バイト列を使う基本的なパーサーを提供するモジュール
+//!
+//! このモジュールは、バイナリ形式のデータや通信プロトコルをパースするための基本的なパーサーを提供します。  
+//! [str_parser]と同じ使い方ができ、[combinator]で組み合わせることができます。  
+//! 引数は[バイト列スライス][slice]となり、結果は各パーサーによって異なります。  
+use super::*;
+
+mod byte;
+pub use byte::*;
+
+mod tag;
+pub use tag::*;
+
+mod take;
+pub use take::*;
+
+mod number;
+pub use number::*;
+
+// 入力の先頭からnバイト分の範囲を求める
+// 入力が足りない場合は、入力の終わりまでを指す
+pub(crate) fn bytes_span(i: &[u8], n: usize) -> Span {
+    Span::new(&i, &&i[n.min(i.len())..])
+}
@@ -0,0 +1,108 @@
+use super::*;
+
+// 数値型を読むパーサーを作成するマクロ
+// $from_bytes には、from_be_bytes か from_le_bytes を指定する
+macro_rules! number_parser {
+    ($($name:ident, $t:ty, $from_bytes:ident, $endian:literal;)*) => {
+        $(
+            #[doc = concat!("バイト列から", $endian, "の`", stringify!($t), "`を読む")]
+            ///
+            #[doc = concat!("先頭の", stringify!($t), "のサイズ分のバイトを、", $endian, "で数値に変換する [Parser][super::Parser] です。")]
+            ///
+            /// ## エラー時
+            /// - 入力が足りない場合、[エラー][super::error::Error]が返却されます。
+            ///   - [kind][super::error::Error::kind]が [Incomplete][super::ErrorKind::Incomplete] になり、不足しているバイト数が入ります。
+            pub fn $name(i: &[u8]) -> (&[u8], Result<$t, Error>) {
+                const SIZE: usize = std::mem::size_of::<$t>();
+                match i.first_chunk::<SIZE>() {
+                    Some(bytes) => (&i[SIZE..], Ok(<$t>::$from_bytes(*bytes))),
+                    None => {
+                        let err = Error::new(ErrorKind::Incomplete(SIZE - i.len()), bytes_span(i, SIZE))
+                            .with_expected(concat!($endian, " ", stringify!($t)));
+                        (i, Err(err))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+// 8ビットの型は1バイトなのでエンディアンの影響を受けないが、対称性のために両方用意する
+number_parser! {
+    be_u8, u8, from_be_bytes, "ビッグエンディアン";
+    be_i8, i8, from_be_bytes, "ビッグエンディアン";
+    be_u16, u16, from_be_bytes, "ビッグエンディアン";
+    be_u32, u32, from_be_bytes, "ビッグエンディアン";
+    be_u64, u64, from_be_bytes, "ビッグエンディアン";
+    be_i16, i16, from_be_bytes, "ビッグエンディアン";
+    be_i32, i32, from_be_bytes, "ビッグエンディアン";
+    be_i64, i64, from_be_bytes, "ビッグエンディアン";
+    be_f32, f32, from_be_bytes, "ビッグエンディアン";
+    be_f64, f64, from_be_bytes, "ビッグエンディアン";
+    le_u8, u8, from_le_bytes, "リトルエンディアン";
+    le_i8, i8, from_le_bytes, "リトルエンディアン";
+    le_u16, u16, from_le_bytes, "リトルエンディアン";
+    le_u32, u32, from_le_bytes, "リトルエンディアン";
+    le_u64, u64, from_le_bytes, "リトルエンディアン";
+    le_i16, i16, from_le_bytes, "リトルエンディアン";
+    le_i32, i32, from_le_bytes, "リトルエンディアン";
+    le_i64, i64, from_le_bytes, "リトルエンディアン";
+    le_f32, f32, from_le_bytes, "リトルエンディアン";
+    le_f64, f64, from_le_bytes, "リトルエンディアン";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::combinator::*;
+
+    // ビッグエンディアンとリトルエンディアン
+    #[test]
+    fn endian_test() {
+        let base: &[u8] = &[0x12, 0x34, 0x56, 0x78, 0xff];
+        assert_eq!(be_u32(base), (&[0xff][..], Ok(0x1234_5678)));
+        assert_eq!(le_u32(base), (&[0xff][..], Ok(0x7856_3412)));
+        assert_eq!(be_i16(&[0xff, 0xfe]), (&[][..], Ok(-2)));
+        assert_eq!(le_f64(&1.5f64.to_le_bytes()), (&[][..], Ok(1.5)));
+        assert_eq!(be_f32(&(-0.25f32).to_be_bytes()), (&[][..], Ok(-0.25)));
+    }
+
+    // 8ビットの型
+    #[test]
+    fn byte_size_test() {
+        let base: &[u8] = &[0xfe, 0x7f];
+        assert_eq!(be_u8(base), (&[0x7f][..], Ok(0xfe)));
+        assert_eq!(le_u8(base), (&[0x7f][..], Ok(0xfe)));
+        assert_eq!(be_i8(base), (&[0x7f][..], Ok(-2)));
+        assert_eq!(le_i8(&base[1..]), (&[][..], Ok(127)));
+        let (rest, result) = le_i8(&[]);
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::Incomplete(1));
+        assert_eq!(rest, &[]);
+    }
+
+    // 入力が足りない
+    #[test]
+    fn incomplete_test() {
+        let base: &[u8] = &[0x00, 0x01, 0x02];
+        let (rest, result) = be_u64(base);
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::Incomplete(5));
+        assert_eq!(rest, &[0x00, 0x01, 0x02]);
+    }
+
+    // コンビネーターと組み合わせる
+    #[test]
+    fn combinator_test() {
+        // マジックナンバー、バージョン、長さ付きのデータ
+        let base: &[u8] = b"MPP\x01\x00\x03abc!";
+        let parser = tag(b"MPP")
+            .cat_b(byte(0x01).sub(byte(0x02)))
+            .cat(be_u16.map(|r| r.map(|n| n as usize)));
+        let (rest, result) = parser(base);
+        let (version, len) = result.unwrap();
+        assert_eq!(version, SubResult::A(0x01));
+        assert_eq!(len, 3);
+        let (rest, data) = take(len)(rest);
+        assert_eq!(data, Ok(&b"abc"[..]));
+        assert_eq!(rest, b"!");
+    }
+}
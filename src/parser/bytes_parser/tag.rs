@@ -0,0 +1,57 @@
+use super::*;
+
+/// バイト列でパースする
+///
+/// 引数に入れたバイト列を条件に解析する [Parser][super::Parser] を**作成**します。  
+/// ファイル形式のマジックナンバーや、プロトコルのキーワードの確認に使います。
+///
+/// # パーサーの仕様について
+/// - 先頭から解析を行います。
+/// ## 成功時
+/// - 条件のバイト列が解析できたら、入力のその部分が結果として返されます。
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseTagError][super::ErrorKind::ParseTagError] になります。
+///   - [span][super::error::Error::span]は、条件のバイト列と同じ長さ分の入力を指します。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::bytes_parser::tag;
+///
+/// let input: &[u8] = b"GET /index.html";
+/// let (rest, result) = tag(b"GET")(input);
+/// assert_eq!(result, Ok(&b"GET"[..]));
+/// assert_eq!(rest, b" /index.html");
+/// ```
+pub fn tag<'a>(t: &[u8]) -> impl Parser<&'a [u8], Result<&'a [u8], Error>> {
+    let label = format!("b\"{}\"", t.escape_ascii());
+    token_parser::tokens_or(t.to_vec(), move |span| {
+        Error::new(ErrorKind::ParseTagError, span).with_expected(&label)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 正常系
+    #[test]
+    fn success_test() {
+        let base: &[u8] = b"\x89PNG\r\n";
+        let (rest, result) = tag(b"\x89PNG")(base);
+        assert_eq!(result, Ok(&b"\x89PNG"[..]));
+        assert_eq!(rest, b"\r\n");
+    }
+
+    // 異常系: バイト列が異なる
+    #[test]
+    fn failure_test() {
+        let base: &[u8] = b"POST /";
+        let (rest, result) = tag(b"GET")(base);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::ParseTagError);
+        assert_eq!(err.span().range(&base), 0..3);
+        assert_eq!(err.expected(), ["b\"GET\""]);
+        assert_eq!(rest, b"POST /");
+    }
+}
@@ -0,0 +1,136 @@
+use super::*;
+
+/// 指定したバイト数を切り出す
+///
+/// 先頭からnバイトを切り出す [Parser][super::Parser] を**作成**します。
+///
+/// ## 成功時
+/// - 切り出したバイト列が、結果として返されます。
+/// ## エラー時
+/// - 入力がnバイトに満たない場合、[エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [Incomplete][super::ErrorKind::Incomplete] になり、不足しているバイト数が入ります。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::{bytes_parser::take, ErrorKind};
+///
+/// let input: &[u8] = b"abcdef";
+/// assert_eq!(take(4)(input), (&b"ef"[..], Ok(&b"abcd"[..])));
+///
+/// let (_, result) = take(8)(input);
+/// assert_eq!(result.unwrap_err().kind(), &ErrorKind::Incomplete(2));
+/// ```
+pub fn take<'a>(n: usize) -> impl Parser<&'a [u8], Result<&'a [u8], Error>> {
+    move |i: &'a [u8]| match i.len() >= n {
+        true => (&i[n..], Ok(&i[..n])),
+        false => {
+            let err = Error::new(ErrorKind::Incomplete(n - i.len()), bytes_span(i, n))
+                .with_expected(format!("{} bytes", n));
+            (i, Err(err))
+        }
+    }
+}
+
+/// 指定したバイト列が現れるまで切り出す
+///
+/// 条件のバイト列が現れる手前までを切り出す [Parser][super::Parser] を**作成**します。  
+/// 条件のバイト列は消費されません。
+///
+/// ## 成功時
+/// - 切り出したバイト列が、結果として返されます。
+/// ## エラー時
+/// - 条件のバイト列が見つからなかった場合、[エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseTakeUntilError][super::ErrorKind::ParseTakeUntilError] になります。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::bytes_parser::take_until;
+///
+/// let input: &[u8] = b"Host: example.com\r\n\r\n";
+/// let (rest, result) = take_until(b"\r\n")(input);
+/// assert_eq!(result, Ok(&b"Host: example.com"[..]));
+/// assert_eq!(rest, b"\r\n\r\n");
+/// ```
+pub fn take_until<'a>(pattern: &[u8]) -> impl Parser<&'a [u8], Result<&'a [u8], Error>> {
+    let pattern = pattern.to_vec();
+    move |i: &'a [u8]| {
+        let found = match pattern.is_empty() {
+            true => Some(0),
+            false => i.windows(pattern.len()).position(|w| w == pattern),
+        };
+        match found {
+            Some(p) => (&i[p..], Ok(&i[..p])),
+            None => {
+                let err = Error::new(ErrorKind::ParseTakeUntilError, Span::at(&&i[i.len()..]))
+                    .with_expected(format!("b\"{}\"", pattern.escape_ascii()));
+                (i, Err(err))
+            }
+        }
+    }
+}
+
+/// 条件を満たすバイトが続く限り切り出す
+///
+/// 引数に入れた関数を条件に、先頭からバイト列を切り出す [Parser][super::Parser] を**作成**します。
+///
+/// ## 成功時
+/// - 条件を満たした部分が、結果として返されます。
+/// - 一つも満たさなかった場合は、空のバイト列が返されます。このパーサーはエラーを返すことはありません。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::bytes_parser::take_while;
+///
+/// let input: &[u8] = b"1234 rest";
+/// let (rest, result) = take_while(|b| b.is_ascii_digit())(input);
+/// assert_eq!(result, Ok(&b"1234"[..]));
+/// assert_eq!(rest, b" rest");
+/// ```
+pub fn take_while<'a>(
+    f: impl Fn(u8) -> bool + Clone,
+) -> impl Parser<&'a [u8], Result<&'a [u8], Error>> {
+    token_parser::take_while(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 入力が足りない
+    #[test]
+    fn take_incomplete_test() {
+        let base: &[u8] = &[1, 2, 3];
+        let (rest, result) = take(5)(base);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Incomplete(2));
+        assert_eq!(err.span().range(&base), 0..3);
+        assert_eq!(rest, &[1, 2, 3]);
+    }
+
+    // 見つからない
+    #[test]
+    fn take_until_not_found_test() {
+        let base: &[u8] = b"abc";
+        let (rest, result) = take_until(b"\r\n")(base);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::ParseTakeUntilError);
+        assert_eq!(err.span().range(&base), 3..3);
+        assert_eq!(rest, b"abc");
+    }
+
+    // 先頭に見つかった場合は空
+    #[test]
+    fn take_until_empty_test() {
+        let base: &[u8] = b";abc";
+        assert_eq!(take_until(b";")(base), (&b";abc"[..], Ok(&b""[..])));
+    }
+
+    // 条件を満たさない場合は空
+    #[test]
+    fn take_while_empty_test() {
+        let base: &[u8] = &[0xff, 0x00];
+        let (rest, result) = take_while(|b| b == 0)(base);
+        assert_eq!(result, Ok(&[][..]));
+        assert_eq!(rest, &[0xff, 0x00]);
+    }
+}
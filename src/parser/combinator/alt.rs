@@ -0,0 +1,226 @@
+use std::rc::Rc;
+
+use super::*;
+
+/// 複数の[Parser][crate::parser::Parser]を順番に試す
+///
+/// [alt]で使用するトレイトです。
+/// `Result<T, Error>`を返すパーサーのタプルに実装されています(要素数は1〜16)。
+pub trait Alt<I, T>: Clone {
+    /// 先頭の要素から順に解析を試み、最初に成功した結果を返します。
+    ///
+    /// 全て失敗した場合は、全てのエラーを[Error::merge]でまとめて返します。  
+    /// [致命的なエラー][Error::is_fatal]が返された場合は、残りの要素を試さずにそのエラーを返します。
+    fn choose(&self, i: I) -> (I, Result<T, Error>);
+}
+
+// タプルにAltを実装するマクロ
+macro_rules! impl_alt {
+    ($p0:ident $i0:tt $(, $p:ident $idx:tt)*) => {
+        impl<I, T, $p0, $($p),*> Alt<I, T> for ($p0, $($p,)*)
+        where
+            I: Clone,
+            $p0: Parser<I, Result<T, Error>>,
+            $($p: Parser<I, Result<T, Error>>,)*
+        {
+            fn choose(&self, i: I) -> (I, Result<T, Error>) {
+                let err = match (self.$i0)(i.clone()) {
+                    (rest, Ok(t)) => return (rest, Ok(t)),
+                    (_, Err(e)) if e.is_fatal() => return (i, Err(e)),
+                    (_, Err(e)) => e,
+                };
+                $(
+                    let err = match (self.$idx)(i.clone()) {
+                        (rest, Ok(t)) => return (rest, Ok(t)),
+                        (_, Err(e)) if e.is_fatal() => return (i, Err(e)),
+                        (_, Err(e)) => err.merge(e),
+                    };
+                )*
+                (i, Err(err))
+            }
+        }
+    };
+}
+
+impl_alt!(P0 0);
+impl_alt!(P0 0, P1 1);
+impl_alt!(P0 0, P1 1, P2 2);
+impl_alt!(P0 0, P1 1, P2 2, P3 3);
+impl_alt!(P0 0, P1 1, P2 2, P3 3, P4 4);
+impl_alt!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5);
+impl_alt!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6);
+impl_alt!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7);
+impl_alt!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7, P8 8);
+impl_alt!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7, P8 8, P9 9);
+impl_alt!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7, P8 8, P9 9, P10 10);
+impl_alt!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7, P8 8, P9 9, P10 10, P11 11);
+impl_alt!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7, P8 8, P9 9, P10 10, P11 11, P12 12);
+impl_alt!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7, P8 8, P9 9, P10 10, P11 11, P12 12, P13 13);
+impl_alt!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7, P8 8, P9 9, P10 10, P11 11, P12 12, P13 13, P14 14);
+impl_alt!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7, P8 8, P9 9, P10 10, P11 11, P12 12, P13 13, P14 14, P15 15);
+
+/// 複数のパーサーのうち、最初に成功したものの結果を返す
+///
+/// 同じ型の結果を返すパーサーのタプルを受け取り、先頭から順に解析を試みる [Parser][crate::parser::Parser] を**作成**します。  
+/// [sub][Substitute::sub]を繋げた場合と異なり、結果が[SubResult]で入れ子になりません。
+///
+/// ## 結果とエラー
+/// - 最初に成功したパーサーの結果が返されます。
+/// - 全て失敗した場合は、全てのエラーを[Error::merge]でまとめたエラーが返され、入力は巻き戻されます。
+/// - [致命的なエラー][Error::is_fatal]が返された場合は、残りのパーサーを試さずにそのエラーが返されます。
+///
+/// ## Example
+/// ```
+/// use my_parser_project::parser::{str_parser::string, combinator::*};
+///
+/// let parser = alt((
+///     string("say".to_string()).map(|r| r.map(|_| 1)),
+///     string("exit".to_string()).map(|r| r.map(|_| 2)),
+///     string("ping".to_string()).map(|r| r.map(|_| 3)),
+/// ));
+///
+/// let (_, result) = parser("exit");
+/// assert_eq!(result, Ok(2));
+///
+/// let (_, result) = parser("help");
+/// assert_eq!(result.unwrap_err().to_string(), "expected 'say', 'exit' or 'ping'");
+/// ```
+pub fn alt<I, T>(parsers: impl Alt<I, T>) -> impl Parser<I, Result<T, Error>> {
+    move |i| parsers.choose(i)
+}
+
+/// 配列に入ったパーサーのうち、最初に成功したものの結果を返す
+///
+/// [alt]と同じ動作をしますが、パーサーを[Vec]で受け取ります。  
+/// 実行時に組み立てたキーワードの一覧など、数が決まっていない場合に使います。  
+/// 型の異なるパーサーは、[BoxedParser]に変換することでまとめることができます。
+///
+/// - パーサーが一つもない場合は、常に[Expected][ErrorKind::Expected]エラーを返します。
+///
+/// ## Example
+/// ```
+/// use my_parser_project::parser::{str_parser::{char, num}, combinator::*, Error};
+///
+/// let parsers: Vec<BoxedParser<&str, Result<u64, Error>>> = vec![
+///     num.boxed(),
+///     char('*').map(|r| r.map(|_| 0)).boxed(),
+/// ];
+/// let parser = choice(parsers);
+///
+/// let (_, result) = parser("*");
+/// assert_eq!(result, Ok(0));
+/// ```
+pub fn choice<I, T, F>(parsers: Vec<F>) -> impl Parser<I, Result<T, Error>>
+where
+    I: Input,
+    F: Fn(I) -> (I, Result<T, Error>),
+{
+    // パーサーがCloneでなくても良いように、Rcで共有する
+    let parsers: Rc<[F]> = parsers.into();
+    move |i: I| {
+        let mut err: Option<Error> = None;
+        for p in parsers.iter() {
+            match p(i.clone()) {
+                (rest, Ok(t)) => return (rest, Ok(t)),
+                (_, Err(e)) if e.is_fatal() => return (i, Err(e)),
+                (_, Err(e)) => {
+                    err = Some(match err {
+                        Some(err) => err.merge(e),
+                        None => e,
+                    })
+                }
+            }
+        }
+        let err = err.unwrap_or_else(|| Error::new(ErrorKind::Expected, Span::at(&i)));
+        (i, Err(err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 最初に成功した結果が返される
+    #[test]
+    fn alt_success_test() {
+        let input = "+1";
+        let parser = alt((
+            str_parser::char('*'),
+            str_parser::char('+'),
+            str_parser::char('-'),
+        ));
+        let (rest, result) = parser(input);
+        assert_eq!(result, Ok('+'));
+        assert_eq!(rest, "1");
+    }
+
+    // 全て失敗した場合はエラーがまとめられる
+    #[test]
+    fn alt_failure_test() {
+        let input = "/1";
+        let parser = alt((
+            str_parser::char('*'),
+            str_parser::char('+'),
+            str_parser::char('-'),
+        ));
+        let (rest, result) = parser(input);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Expected);
+        assert_eq!(err.expected(), ["'*'", "'+'", "'-'"]);
+        assert_eq!(rest, "/1");
+    }
+
+    // 16個のパーサー
+    #[test]
+    fn alt_16_test() {
+        let c = str_parser::char;
+        let parser = alt((
+            c('0'),
+            c('1'),
+            c('2'),
+            c('3'),
+            c('4'),
+            c('5'),
+            c('6'),
+            c('7'),
+            c('8'),
+            c('9'),
+            c('a'),
+            c('b'),
+            c('c'),
+            c('d'),
+            c('e'),
+            c('f'),
+        ));
+        let (_, result) = parser("f");
+        assert_eq!(result, Ok('f'));
+    }
+
+    // 配列で渡したパーサー
+    #[test]
+    fn choice_success_test() {
+        let keywords = ["say", "exit", "ping"];
+        let parsers = keywords
+            .iter()
+            .map(|k| str_parser::string(k.to_string()))
+            .collect();
+        let parser = choice(parsers);
+        let (rest, result) = parser("ping!");
+        assert_eq!(result, Ok("ping".to_string()));
+        assert_eq!(rest, "!");
+
+        let (_, result) = parser("help");
+        assert_eq!(result.unwrap_err().expected().len(), 3);
+    }
+
+    // パーサーが一つもない場合
+    #[test]
+    fn choice_empty_test() {
+        type NumParser = fn(&str) -> (&str, Result<u64, Error>);
+        let parsers: Vec<NumParser> = vec![];
+        let parser = choice(parsers);
+        let (rest, result) = parser("abc");
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::Expected);
+        assert_eq!(rest, "abc");
+    }
+}
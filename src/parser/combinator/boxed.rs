@@ -0,0 +1,169 @@
+use std::rc::Rc;
+
+use super::*;
+
+/// 複製できるパーサーのトレイトオブジェクト
+///
+/// [Parser][crate::parser::Parser]は[Clone]を必要とするため、そのままでは`Box<dyn Fn>`にできません。  
+/// このトレイトを使うと、`Box<dyn DynParser<I, R>>`として型を消したまま複製できます。  
+/// [Parser][crate::parser::Parser]を実装した全ての型に実装されています。
+///
+/// 通常は[BoxedParser]を使ってください。
+pub trait DynParser<I, R>: Fn(I) -> (I, R) {
+    /// 自身を複製し、[Box]に入れて返します
+    fn clone_box<'a>(&self) -> Box<dyn DynParser<I, R> + 'a>
+    where
+        Self: 'a;
+}
+
+impl<I, R, P: Parser<I, R>> DynParser<I, R> for P {
+    fn clone_box<'a>(&self) -> Box<dyn DynParser<I, R> + 'a>
+    where
+        Self: 'a,
+    {
+        Box::new(self.clone())
+    }
+}
+
+impl<'a, I: 'a, R: 'a> Clone for Box<dyn DynParser<I, R> + 'a> {
+    fn clone(&self) -> Self {
+        self.as_ref().clone_box()
+    }
+}
+
+/// 型を消したパーサー
+///
+/// 入力と結果の型が同じであれば、作り方の異なるパーサーを同じ型として扱えます。  
+/// [Vec]や[HashMap][std::collections::HashMap]、構造体のフィールドに入れたい場合に使います。  
+/// [Parser][crate::parser::Parser]を実装しているため、他のパーサーと同じように組み合わせられます。
+///
+/// [boxed][Boxed::boxed]や[shared][Boxed::shared]で作成します。
+pub type BoxedParser<'a, I, R> = Box<dyn DynParser<I, R> + 'a>;
+
+/// パーサーを[BoxedParser]に変換するメソッドを提供する
+pub trait Boxed<I, R> {
+    /// パーサーを[Box]に入れて、型を消します
+    ///
+    /// 複製すると、中のパーサーも複製されます。
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use my_parser_project::parser::{
+    ///     combinator::{Boxed, BoxedParser, Map},
+    ///     str_parser::{char, int},
+    ///     Error,
+    /// };
+    ///
+    /// // 設定から文法を組み立てる
+    /// let mut values: HashMap<&str, BoxedParser<&str, Result<i64, Error>>> = HashMap::new();
+    /// values.insert("int", int.boxed());
+    /// values.insert("zero", char('0').map(|r| r.map(|_| 0)).boxed());
+    ///
+    /// let parser = values["int"].clone();
+    /// assert_eq!(parser("42"), ("", Ok(42)));
+    /// ```
+    fn boxed<'a>(self) -> BoxedParser<'a, I, R>
+    where
+        Self: 'a;
+
+    /// パーサーを[Rc]で共有して、型を消します
+    ///
+    /// [boxed][Boxed::boxed]と同じですが、複製しても中のパーサーは複製されず、共有されます。  
+    /// 大きな文法を何度も複製する場合は、こちらの方が軽くなります。
+    ///
+    /// # Example
+    /// ```rust
+    /// use my_parser_project::parser::{combinator::Boxed, str_parser::char};
+    ///
+    /// let parser = char('a').shared();
+    /// let cloned = parser.clone();
+    /// assert_eq!(cloned("ab"), ("b", Ok('a')));
+    /// ```
+    fn shared<'a>(self) -> BoxedParser<'a, I, R>
+    where
+        Self: 'a;
+}
+
+impl<I, R, P> Boxed<I, R> for P
+where
+    P: Parser<I, R>,
+{
+    fn boxed<'a>(self) -> BoxedParser<'a, I, R>
+    where
+        Self: 'a,
+    {
+        Box::new(self)
+    }
+
+    fn shared<'a>(self) -> BoxedParser<'a, I, R>
+    where
+        Self: 'a,
+    {
+        let p = Rc::new(self);
+        Box::new(move |i| p(i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::str_parser::{char, num, string};
+
+    // 型の異なるパーサーをVecにまとめる
+    #[test]
+    fn vec_test() {
+        let parsers: Vec<BoxedParser<&str, Result<u64, Error>>> = vec![
+            num.boxed(),
+            char('*').map(|r| r.map(|_| 0)).boxed(),
+            string("one".to_string()).map(|r| r.map(|_| 1)).shared(),
+        ];
+        let parser = choice(parsers);
+        assert_eq!(parser("one"), ("", Ok(1)));
+        assert_eq!(parser("*"), ("", Ok(0)));
+        assert_eq!(parser("12"), ("", Ok(12)));
+    }
+
+    // 構造体のフィールドに入れる
+    #[test]
+    fn field_test() {
+        struct Grammar<'a> {
+            sign: BoxedParser<'a, &'a str, Result<char, Error>>,
+        }
+        let grammar = Grammar {
+            sign: char('+')
+                .sub(char('-'))
+                .map(|r| {
+                    r.map(|s| match s {
+                        SubResult::A(c) | SubResult::B(c) => c,
+                    })
+                })
+                .boxed(),
+        };
+        // 他のパーサーと組み合わせられる
+        let parser = grammar.sign.clone().cat(num);
+        assert_eq!(parser("-3"), ("", Ok(('-', 3))));
+    }
+
+    // 共有したパーサーは、複製しても同じものを指す
+    #[test]
+    fn shared_test() {
+        let counter = Rc::new(());
+        let c = counter.clone();
+        let parser = (move |i: &'static str| (i, Ok::<_, Error>(Rc::strong_count(&c)))).shared();
+        let cloned = parser.clone();
+        // 複製してもカウンターは増えない
+        assert_eq!(cloned("").1, Ok(2));
+        assert_eq!(Rc::strong_count(&counter), 2);
+        let _boxed = parser.clone_box();
+        assert_eq!(Rc::strong_count(&counter), 2);
+    }
+
+    // 型を消したパーサーを複製できる
+    #[test]
+    fn dyn_parser_test() {
+        let boxed: Box<dyn DynParser<&str, Result<char, Error>>> = Box::new(char('a'));
+        let cloned = boxed.clone();
+        assert_eq!(cloned("ab"), ("b", Ok('a')));
+    }
+}
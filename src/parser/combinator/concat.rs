@@ -2,16 +2,24 @@ use super::*;
 
 /// パーサーを繋げるメソッドを提供する
 ///
-/// [crate::parser::Parser] 型の関数オブジェクトを連結させるためのメソッドを提供します。
-
-pub trait Concat<I, O1, O2> {
+/// [Result]型を返す [crate::parser::Parser] 型の関数オブジェクトを連結させるためのメソッドを提供します。
+///
+/// # 失敗時の動作について
+/// - 連結したパーサーは、先頭から順に解析を行います。
+/// - いずれかのパーサーが[Err]を返した時点で解析を中断し、以降のパーサーは実行されません。
+/// - 失敗した場合、入力は**解析前の状態に巻き戻されて**返されます。
+///   連結したパーサーの途中まで解析された状態で返ることはありません。
+///
+/// 失敗しても後続のパーサーを実行したい場合は、[ConcatUncheck]を使用してください。
+pub trait Concat<I, A, E> {
     /// パーサーを連結させ、両方の結果を返す
     ///
     /// # 結果について
-    /// `(I, (O1, O2))`二重タプルで返します  
+    /// `(I, Result<(A, B), E>)`形式で返します
     /// - Iはパース後に残った部分
-    /// - O1はメソッドを呼び出す元のパーサーが返した結果
-    /// - O2はメソッドの引数に含めたパーサーが返した結果
+    /// - Aはメソッドを呼び出す元のパーサーが返した結果
+    /// - Bはメソッドの引数に含めたパーサーが返した結果
+    /// - Eは最初に失敗したパーサーのエラー
     ///
     /// # Example
     /// ```
@@ -21,18 +29,30 @@ pub trait Concat<I, O1, O2> {
     /// let parser = char('*').cat(num);
     /// let (_, result) = parser(input);
     ///
-    /// assert_eq!(result, (Ok('*'), Ok(123)))
+    /// assert_eq!(result, Ok(('*', 123)))
     /// ```
     ///
-    fn cat(self, p: impl Parser<I, O2>) -> impl Parser<I, (O1, O2)>;
+    /// 失敗した場合は入力が巻き戻されます。
+    /// ```
+    /// use my_parser_project::parser::{str_parser::{char, num}, combinator::Concat};
+    ///
+    /// let input = "*abc";
+    /// let parser = char('*').cat(num);
+    /// let (rest, result) = parser(input);
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(rest, "*abc");
+    /// ```
+    fn cat<B>(self, p: impl Parser<I, Result<B, E>>) -> impl Parser<I, Result<(A, B), E>>;
 
     /// パーサーを連結させ、メソッド呼び出し元のパーサーが返した結果を返す
     ///
     /// 結果を必要としないパーサーを組み合わせるときに便利です
     /// # 結果について
-    /// `(I, O1)`タプルで返します。
+    /// `(I, Result<A, E>)`形式で返します。
     /// - Iはパース後に残った部分
-    /// - O1はメソッドを呼び出す元のパーサーが返した結果
+    /// - Aはメソッドを呼び出す元のパーサーが返した結果
+    /// - Eは最初に失敗したパーサーのエラー
     ///
     /// # Example
     /// ```
@@ -45,15 +65,16 @@ pub trait Concat<I, O1, O2> {
     /// assert_eq!(result, Ok('*'))
     /// ```
     ///
-    fn cat_a(self, p: impl Parser<I, O2>) -> impl Parser<I, O1>;
+    fn cat_a<B>(self, p: impl Parser<I, Result<B, E>>) -> impl Parser<I, Result<A, E>>;
 
     /// パーサーを連結させ、メソッド引数に含めたパーサーが返した結果を返す
     ///
     /// 結果を必要としないパーサーを組み合わせるときに便利です
     /// # 結果について
-    /// `(I, O2)`二重タプルで返します
+    /// `(I, Result<B, E>)`形式で返します
     /// - Iはパース後に残った部分
-    /// - O2はメソッドの引数に含めたパーサーが返した結果
+    /// - Bはメソッドの引数に含めたパーサーが返した結果
+    /// - Eは最初に失敗したパーサーのエラー
     ///
     /// # Example
     /// ```
@@ -66,15 +87,117 @@ pub trait Concat<I, O1, O2> {
     /// assert_eq!(result, Ok(123))
     /// ```
     ///
-    fn cat_b(self, p: impl Parser<I, O2>) -> impl Parser<I, O2>;
+    fn cat_b<B>(self, p: impl Parser<I, Result<B, E>>) -> impl Parser<I, Result<B, E>>;
+}
+
+// 実装
+impl<I, A, E, P> Concat<I, A, E> for P
+where
+    I: Clone,
+    P: Parser<I, Result<A, E>>,
+{
+    fn cat<B>(self, p: impl Parser<I, Result<B, E>>) -> impl Parser<I, Result<(A, B), E>> {
+        move |i: I| {
+            // self(メソッド呼び出し元パーサー)
+            // p(メソッド引数パーサー)
+            // の順でパースし、失敗したら元の入力に巻き戻す
+            let (rest, a) = match self(i.clone()) {
+                (rest, Ok(a)) => (rest, a),
+                (_, Err(e)) => return (i, Err(e)),
+            };
+            match p(rest) {
+                (rest, Ok(b)) => (rest, Ok((a, b))),
+                (_, Err(e)) => (i, Err(e)),
+            }
+        }
+    }
+
+    fn cat_a<B>(self, p: impl Parser<I, Result<B, E>>) -> impl Parser<I, Result<A, E>> {
+        // o2の結果は破棄する
+        self.cat(p).map(|r| r.map(|(a, _)| a))
+    }
+
+    fn cat_b<B>(self, p: impl Parser<I, Result<B, E>>) -> impl Parser<I, Result<B, E>> {
+        // o1の結果は破棄する
+        self.cat(p).map(|r| r.map(|(_, b)| b))
+    }
+}
+
+/// パーサーを結果に関わらず繋げるメソッドを提供する
+///
+/// [crate::parser::Parser] 型の関数オブジェクトを連結させるためのメソッドを提供します。
+/// [Concat]とは異なり、呼び出し元のパーサーが失敗しても引数のパーサーを実行します。
+/// 戻り値が[Result]でない、[trimer][crate::parser::str_parser::trimer]などのパーサーも連結できます。
+pub trait ConcatUncheck<I, O1> {
+    /// パーサーを連結させ、両方の結果を返す
+    ///
+    /// # 結果について
+    /// `(I, (O1, O2))`二重タプルで返します
+    /// - Iはパース後に残った部分
+    /// - O1はメソッドを呼び出す元のパーサーが返した結果
+    /// - O2はメソッドの引数に含めたパーサーが返した結果
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::{char, num}, combinator::ConcatUncheck};
+    ///
+    /// let input = "*123";
+    /// let parser = char('*').cat_uncheck(num);
+    /// let (_, result) = parser(input);
+    ///
+    /// assert_eq!(result, (Ok('*'), Ok(123)))
+    /// ```
+    ///
+    fn cat_uncheck<O2>(self, p: impl Parser<I, O2>) -> impl Parser<I, (O1, O2)>;
+
+    /// パーサーを連結させ、メソッド呼び出し元のパーサーが返した結果を返す
+    ///
+    /// # 結果について
+    /// `(I, O1)`タプルで返します。
+    /// - Iはパース後に残った部分
+    /// - O1はメソッドを呼び出す元のパーサーが返した結果
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::{char, trimer}, combinator::ConcatUncheck};
+    ///
+    /// let input = "*   123";
+    /// let parser = char('*').cat_a_uncheck(trimer);
+    /// let (rest, result) = parser(input);
+    ///
+    /// assert_eq!(result, Ok('*'));
+    /// assert_eq!(rest, "123");
+    /// ```
+    ///
+    fn cat_a_uncheck<O2>(self, p: impl Parser<I, O2>) -> impl Parser<I, O1>;
+
+    /// パーサーを連結させ、メソッド引数に含めたパーサーが返した結果を返す
+    ///
+    /// # 結果について
+    /// `(I, O2)`タプルで返します
+    /// - Iはパース後に残った部分
+    /// - O2はメソッドの引数に含めたパーサーが返した結果
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::{num, trimer}, combinator::ConcatUncheck};
+    ///
+    /// let input = "   123";
+    /// let parser = trimer.cat_b_uncheck(num);
+    /// let (_, result) = parser(input);
+    ///
+    /// assert_eq!(result, Ok(123))
+    /// ```
+    ///
+    fn cat_b_uncheck<O2>(self, p: impl Parser<I, O2>) -> impl Parser<I, O2>;
 }
 
 // 実装
-impl<I, O1, O2, P> Concat<I, O1, O2> for P
+impl<I, O1, P> ConcatUncheck<I, O1> for P
 where
     P: Parser<I, O1>,
 {
-    fn cat(self, p: impl Parser<I, O2>) -> impl Parser<I, (O1, O2)> {
+    fn cat_uncheck<O2>(self, p: impl Parser<I, O2>) -> impl Parser<I, (O1, O2)> {
         move |i| {
             // self(メソッド呼び出し元パーサー)
             // p(メソッド引数パーサー)
@@ -85,7 +208,7 @@ where
         }
     }
 
-    fn cat_a(self, p: impl Parser<I, O2>) -> impl Parser<I, O1> {
+    fn cat_a_uncheck<O2>(self, p: impl Parser<I, O2>) -> impl Parser<I, O1> {
         move |i| {
             let (i, o1) = self(i);
             // o2の結果は破棄する
@@ -94,7 +217,7 @@ where
         }
     }
 
-    fn cat_b(self, p: impl Parser<I, O2>) -> impl Parser<I, O2> {
+    fn cat_b_uncheck<O2>(self, p: impl Parser<I, O2>) -> impl Parser<I, O2> {
         move |i| {
             // o1の結果は破棄する
             let (i, _) = self(i);
@@ -113,10 +236,9 @@ mod tests {
     fn cat_success_test() {
         let base = "*123";
         let parser = str_parser::char('*').cat(str_parser::num);
-        let (i, (r1, r2)) = parser(base);
+        let (i, r) = parser(base);
         // パースした結果
-        assert_eq!(r1, Ok('*'));
-        assert_eq!(r2, Ok(123));
+        assert_eq!(r, Ok(('*', 123)));
         // 取り残された文字
         assert_eq!(i, "");
     }
@@ -152,6 +274,38 @@ mod tests {
         let base = "+-abc";
         let parser = str_parser::char('-').cat(str_parser::char('+'));
 
+        let (i, r) = parser(base);
+        // パースした結果
+        assert_eq!(r.unwrap_err().kind(), &ErrorKind::ParseCharError);
+        // 何も消費されていない
+        assert_eq!(i, "+-abc");
+    }
+
+    // 二つ目のパーサーで失敗した場合も巻き戻される
+    #[test]
+    fn cat_rollback_test() {
+        let base = "*abc";
+        let parser = str_parser::char('*')
+            .cat(str_parser::char('a'))
+            .cat_b(str_parser::num);
+
+        let (i, r) = parser(base);
+        // パースした結果
+        assert!(matches!(
+            r.unwrap_err().kind(),
+            &ErrorKind::ParseNumError(_)
+        ));
+        // '*'と'a'も消費されていない
+        assert_eq!(i, "*abc");
+    }
+
+    // cat_uncheckは失敗しても後続のパーサーを実行する
+    #[test]
+    fn cat_uncheck_error_test() {
+        // パースしたい文字列の並び方が、パーサーの並び方と異なる
+        let base = "+-abc";
+        let parser = str_parser::char('-').cat_uncheck(str_parser::char('+'));
+
         let (i, (r1, r2)) = parser(base);
         // パースした結果
         assert_eq!(r1.unwrap_err().kind(), &ErrorKind::ParseCharError);
@@ -159,4 +313,16 @@ mod tests {
         // 取り残された文字
         assert_eq!(i, "-abc");
     }
+
+    // 戻り値がResultでないパーサーも連結できる
+    #[test]
+    fn cat_b_uncheck_test() {
+        let base = "  123";
+        let parser = str_parser::trimer.cat_b_uncheck(str_parser::num);
+        let (i, r) = parser(base);
+        // パースした結果
+        assert_eq!(r, Ok(123));
+        // 取り残された文字
+        assert_eq!(i, "");
+    }
 }
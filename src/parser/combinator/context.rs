@@ -0,0 +1,92 @@
+use super::*;
+
+/// 失敗した時に、文脈ラベルを付ける
+///
+/// 引数のパーサーが失敗した場合に、エラーに文脈ラベルを付ける [Parser][crate::parser::Parser] を**作成**します。  
+/// 深い位置で失敗した時に、何を解析している途中だったかを表示できるようになります。
+///
+/// # 結果とエラー
+/// - 成功した場合は、結果をそのまま返します。
+/// - 失敗した場合は、[Error::context]でラベルを付けたエラーを返します。
+///   - [kind][Error::kind]が [Context][ErrorKind::Context] になります。
+///   - 入れ子にした場合、外側のラベルから順に表示されます。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::{
+///     combinator::{context, Concat},
+///     str_parser::{char, string, take_while1},
+/// };
+///
+/// let method = context("method", string("GET".to_string()));
+/// let path = take_while1(|c| !c.is_whitespace());
+/// let request_line = context("request line", method.cat_a(char(' ')).cat(path));
+///
+/// let (_, result) = request_line("PUT /index.html");
+/// let err = result.unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "in request line → in method → failed parse to string"
+/// );
+///
+/// // sourceで文脈をたどれる
+/// use std::error::Error;
+/// let method = err.source().unwrap();
+/// assert_eq!(method.to_string(), "in method → failed parse to string");
+/// ```
+pub fn context<I, T>(
+    label: impl Into<String>,
+    p: impl Parser<I, Result<T, Error>>,
+) -> impl Parser<I, Result<T, Error>> {
+    let label = label.into();
+    move |i: I| match p(i) {
+        (rest, Ok(t)) => (rest, Ok(t)),
+        (rest, Err(e)) => (rest, Err(e.context(label.clone()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::str_parser::{char, num};
+
+    // 範囲と期待されていた入力は、元のエラーのものが引き継がれる
+    #[test]
+    fn keep_span_test() {
+        let src = "(x";
+        let parser = context("group", char('(').cat_b(context("number", num)));
+        let (rest, result) = parser(src);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Context("group".to_string()));
+        assert_eq!(err.span().range(&src), 1..2);
+        assert_eq!(err.expected(), ["number"]);
+        assert_eq!(err.contexts().collect::<Vec<_>>(), ["group", "number"]);
+        assert!(matches!(err.root().kind(), ErrorKind::ParseNumError(_)));
+        assert_eq!(rest, src);
+    }
+
+    // sourceで元のエラーまでたどれる
+    #[test]
+    fn source_chain_test() {
+        let (_, result) = context("a", context("b", num))("x");
+        let err = result.unwrap_err();
+        let mut chain = Vec::new();
+        let mut e: Option<&dyn std::error::Error> = Some(&err);
+        while let Some(cur) = e {
+            chain.push(cur.to_string());
+            e = cur.source();
+        }
+        assert_eq!(chain.len(), 4);
+        assert!(chain[0].starts_with("in a → in b → failed parse to number"));
+        assert!(chain[1].starts_with("in b → failed parse to number"));
+        assert!(chain[2].starts_with("failed parse to number"));
+        // 最後はParseIntError
+        assert_eq!(chain[3], "cannot parse integer from empty string");
+    }
+
+    // 成功した場合は何もしない
+    #[test]
+    fn success_test() {
+        assert_eq!(context("number", num)("12"), ("", Ok(12)));
+    }
+}
@@ -0,0 +1,99 @@
+use super::*;
+
+/// 失敗した時に、致命的なエラーにする
+///
+/// 引数のパーサーが失敗した場合に、エラーを[致命的なエラー][Error::is_fatal]にする [Parser][crate::parser::Parser] を**作成**します。  
+/// `if`のようなキーワードを読んだ後など、ここで失敗したら他の候補は当てはまらないと分かっている位置で使います。
+///
+/// 致命的なエラーを受け取ったコンビネーターは、次のように動作します。
+/// - [sub][Substitute::sub]や[alt]は、残りの候補を試さずにそのエラーを返します。
+/// - [many0][Repeat::many0]などの繰り返しは、回数に関わらず中断してそのエラーを返します。
+/// - [opt][Optional::opt]などの省略は、省略せずにそのエラーを返します。
+///
+/// 他の候補を試さなくなるため、分かりやすいエラーを返せるようになり、無駄な解析も減ります。
+///
+/// # 結果とエラー
+/// - 成功した場合は、結果をそのまま返します。
+/// - 失敗した場合は、[Error::cut]で致命的にしたエラーを返します。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::{
+///     combinator::*,
+///     str_parser::{char, num, string, take_while1},
+/// };
+///
+/// // `if`の後ろには、必ず数字が来る
+/// let if_stmt = string("if ".to_string()).cat_b(cut(num));
+/// let ident = take_while1(|c| c.is_alphabetic()).map(|r| r.map(|_| 0));
+/// let parser = if_stmt.sub(ident);
+///
+/// let src = "if x";
+/// let (rest, result) = parser(src);
+/// let err = result.unwrap_err();
+/// // identを試さずに、数字がないことを報告する
+/// assert!(err.is_fatal());
+/// assert_eq!(err.expected(), ["number"]);
+/// assert_eq!(err.span().range(&src), 3..4);
+/// assert_eq!(rest, src);
+/// ```
+pub fn cut<I, T>(p: impl Parser<I, Result<T, Error>>) -> impl Parser<I, Result<T, Error>> {
+    move |i: I| match p(i) {
+        (rest, Ok(t)) => (rest, Ok(t)),
+        (rest, Err(e)) => (rest, Err(e.cut())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::str_parser::{char, num};
+
+    // 致命的なエラーの後ろの候補は試されない
+    #[test]
+    fn alt_stop_test() {
+        let src = "(x";
+        let group = char('(').cat_b(cut(num)).cat_a(char(')'));
+        let parser = alt((group, char('(').map(|r| r.map(|_| 0))));
+        let (rest, result) = parser(src);
+        let err = result.unwrap_err();
+        assert!(err.is_fatal());
+        assert!(matches!(err.kind(), ErrorKind::ParseNumError(_)));
+        assert_eq!(rest, src);
+    }
+
+    // 繰り返しは、致命的なエラーで中断される
+    #[test]
+    fn many0_stop_test() {
+        let src = "-1-2-x";
+        let parser = char('-').cat_b(cut(num)).many0();
+        let (rest, result) = parser(src);
+        assert_eq!(result.unwrap_err().span().range(&src), 5..6);
+        assert_eq!(rest, src);
+
+        // cutがなければ、成功した所までで終わる
+        let parser = char('-').cat_b(num).many0();
+        assert_eq!(parser(src), ("-x", Ok(vec![1, 2])));
+    }
+
+    // 区切り文字のリストも中断される
+    #[test]
+    fn sep_by_stop_test() {
+        let src = "#1,#2,#x";
+        let parser = char('#').cat_b(cut(num)).sep_by(char(','), Trailing::Allow);
+        let (rest, result) = parser(src);
+        assert_eq!(result.unwrap_err().span().range(&src), 7..8);
+        assert_eq!(rest, src);
+    }
+
+    // 致命的なエラーは、位置に関わらずmergeで優先される
+    #[test]
+    fn merge_test() {
+        let src = "a*";
+        let (_, a) = cut(char('+'))(src);
+        let (_, b) = char('a').cat(char('-'))(src);
+        let err = b.unwrap_err().merge(a.unwrap_err());
+        assert!(err.is_fatal());
+        assert_eq!(err.span().range(&src), 0..1);
+    }
+}
@@ -0,0 +1,212 @@
+use super::*;
+
+/// 入力を消費せずに、先の入力を確認するメソッドを提供する
+///
+/// [Result]型を返す [crate::parser::Parser] 型の関数オブジェクトに実装されています。
+///
+/// # 入力について
+/// - 確認に使ったパーサーは、成功しても失敗しても入力を消費しません。
+/// - 失敗した場合、入力は**解析前の状態に巻き戻されて**返されます。
+pub trait Lookahead<I, T> {
+    /// 入力を消費せずにパーサーを実行する
+    ///
+    /// 結果はそのまま返しますが、入力は常に解析前の状態で返されます。
+    ///
+    /// # Example
+    /// ```rust
+    /// use my_parser_project::parser::{combinator::Lookahead, str_parser::char};
+    ///
+    /// let parser = char('(').peek();
+    /// assert_eq!(parser("(1)"), ("(1)", Ok('(')));
+    /// assert!(parser("1").1.is_err());
+    /// ```
+    fn peek(self) -> impl Parser<I, Result<T, Error>>;
+
+    /// 後ろに続く入力を確認する
+    ///
+    /// 呼び出し元のパーサーが成功した後、引数のパーサーが成功するかを確認します。  
+    /// 引数のパーサーは入力を消費しません。
+    ///
+    /// ## エラー時
+    /// - 引数のパーサーが失敗した場合は、そのエラーを返します。
+    ///
+    /// # Example
+    /// ```rust
+    /// use my_parser_project::parser::{
+    ///     combinator::{alt, eof, Lookahead, Map},
+    ///     str_parser::{satisfy, string},
+    /// };
+    ///
+    /// // "say"の後ろには、空白か入力の終わりが必要
+    /// let space = satisfy(|c| c.is_whitespace()).map(|r| r.map(|_| ()));
+    /// let say = string("say".to_string()).followed_by(alt((space, eof)));
+    /// assert_eq!(say("say hello"), (" hello", Ok("say".to_string())));
+    /// assert_eq!(say("say"), ("", Ok("say".to_string())));
+    /// assert!(say("saying").1.is_err());
+    /// ```
+    fn followed_by<B>(
+        self,
+        p: impl Parser<I, Result<B, Error>>,
+    ) -> impl Parser<I, Result<T, Error>>;
+
+    /// 後ろに続かない入力を確認する
+    ///
+    /// 呼び出し元のパーサーが成功した後、引数のパーサーが**失敗する**かを確認します。  
+    /// 引数のパーサーは入力を消費しません。
+    ///
+    /// ## エラー時
+    /// - 引数のパーサーが成功した場合は、[エラー][Error]を返します。
+    ///   - [kind][Error::kind]が [Unexpected][ErrorKind::Unexpected] になります。
+    ///   - [span][Error::span]は、引数のパーサーが一致した部分を指します。
+    ///
+    /// # Example
+    /// ```rust
+    /// use my_parser_project::parser::{
+    ///     combinator::Lookahead,
+    ///     str_parser::{char, take_while1},
+    /// };
+    ///
+    /// // 後ろに'('が続かないものを変数とする
+    /// let variable = take_while1(|c| c.is_alphabetic()).not_followed_by(char('('));
+    /// assert_eq!(variable("x + 1"), (" + 1", Ok("x")));
+    /// assert!(variable("f(1)").1.is_err());
+    /// ```
+    fn not_followed_by<B>(
+        self,
+        p: impl Parser<I, Result<B, Error>>,
+    ) -> impl Parser<I, Result<T, Error>>;
+}
+
+impl<I, T, P> Lookahead<I, T> for P
+where
+    I: Input,
+    P: Parser<I, Result<T, Error>>,
+{
+    fn peek(self) -> impl Parser<I, Result<T, Error>> {
+        move |i: I| (i.clone(), self(i).1)
+    }
+
+    fn followed_by<B>(
+        self,
+        p: impl Parser<I, Result<B, Error>>,
+    ) -> impl Parser<I, Result<T, Error>> {
+        self.cat_a(p.peek())
+    }
+
+    fn not_followed_by<B>(
+        self,
+        p: impl Parser<I, Result<B, Error>>,
+    ) -> impl Parser<I, Result<T, Error>> {
+        self.cat_a(not(p))
+    }
+}
+
+/// パーサーが失敗することを確認する
+///
+/// 引数のパーサーが失敗した場合に成功する [Parser][crate::parser::Parser] を**作成**します。  
+/// 成功しても失敗しても、入力は消費しません。
+///
+/// ## エラー時
+/// - 引数のパーサーが成功した場合は、[エラー][Error]を返します。
+///   - [kind][Error::kind]が [Unexpected][ErrorKind::Unexpected] になります。
+///   - [span][Error::span]は、引数のパーサーが一致した部分を指します。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::{combinator::not, str_parser::string};
+///
+/// let parser = not(string("//".to_string()));
+/// assert_eq!(parser("/ 2"), ("/ 2", Ok(())));
+/// assert!(parser("// comment").1.is_err());
+/// ```
+pub fn not<I, T>(p: impl Parser<I, Result<T, Error>>) -> impl Parser<I, Result<(), Error>>
+where
+    I: Input,
+{
+    move |i: I| match p(i.clone()) {
+        (rest, Ok(_)) => {
+            let err = Error::new(ErrorKind::Unexpected, Span::new(&i, &rest));
+            (i, Err(err))
+        }
+        (_, Err(_)) => (i, Ok(())),
+    }
+}
+
+/// 入力の終わりを確認する
+///
+/// 残りの入力が空の場合に成功する [Parser][crate::parser::Parser] です。入力は消費しません。
+///
+/// ## エラー時
+/// - 入力が残っている場合は、[エラー][Error]を返します。
+///   - [kind][Error::kind]が [Expected][ErrorKind::Expected] になります。
+///   - [span][Error::span]は、残っている入力の先頭の要素を指します。
+///   - [expected][Error::expected]には、`end of input`が記録されます。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::{combinator::{eof, Concat}, str_parser::int};
+///
+/// let parser = int::<i32>.cat_a(eof);
+/// assert_eq!(parser("42"), ("", Ok(42)));
+///
+/// let (_, result) = parser("42x");
+/// assert_eq!(result.unwrap_err().to_string(), "expected end of input");
+/// ```
+pub fn eof<I: Input>(i: I) -> (I, Result<(), Error>) {
+    match i.input_len() {
+        0 => (i, Ok(())),
+        _ => {
+            let err = Error::new(ErrorKind::Expected, token_parser::elements_span(&i, 1))
+                .with_expected("end of input");
+            (i, Err(err))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::str_parser::{char, num, satisfy};
+
+    // peekは入力を消費しない
+    #[test]
+    fn peek_test() {
+        let (rest, result) = num.peek()("12+");
+        assert_eq!(result, Ok(12));
+        assert_eq!(rest, "12+");
+    }
+
+    // followed_byは、後ろのパーサーの入力を消費しない
+    #[test]
+    fn followed_by_test() {
+        let parser = num.followed_by(char(';'));
+        assert_eq!(parser("1;"), (";", Ok(1)));
+        let (rest, result) = parser("1,");
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::ParseCharError);
+        assert_eq!(err.span().range(&"1,"), 1..2);
+        assert_eq!(rest, "1,");
+    }
+
+    // not_followed_byは、一致した部分を指すエラーを返す
+    #[test]
+    fn not_followed_by_test() {
+        let src = "12ab";
+        let parser = num.not_followed_by(satisfy(|c| c.is_alphabetic()));
+        let (rest, result) = parser(src);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Unexpected);
+        assert_eq!(err.span().range(&src), 2..3);
+        assert_eq!(rest, src);
+    }
+
+    // トークン列でも入力の終わりを確認できる
+    #[test]
+    fn eof_test() {
+        let base: &[u8] = &[1, 2];
+        assert_eq!(eof(&base[2..]), (&base[2..], Ok(())));
+        let (rest, result) = eof(&base[1..]);
+        assert_eq!(result.unwrap_err().span().range(&base), 1..2);
+        assert_eq!(rest, &[2]);
+    }
+}
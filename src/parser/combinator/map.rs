@@ -7,7 +7,7 @@ pub trait Map<I, O> {
     /// クロージャーを受け取り、mapメソッドを呼び出す手前までのパーサーが出力した結果に、関数を適用します。
     ///
     /// # ヒント
-    /// - [.cat][concat::Concat::cat]メソッドは(`メソッド呼び出し元`, `メソッド引数`)形式で出力されます
+    /// - [.cat][concat::Concat::cat]メソッドは`Result<(メソッド呼び出し元, メソッド引数), E>`形式で出力されます
     /// - [.sub][substitute::Substitute::sub]メソッドは[substitute::SubResult]で出力されます
     fn map<T>(self, f: impl Fn(O) -> T + Clone) -> impl Parser<I, T>;
 }
@@ -0,0 +1,150 @@
+use super::*;
+
+/// 失敗する可能性のある関数を、パーサーの結果に適用するメソッドを提供する
+///
+/// 戻り値に[Result]型を用いる[Parser][crate::parser::Parser]で使うことができます。  
+/// 「ポート番号が範囲外」のような、文法ではなく意味の誤りを、位置付きのエラーとして返すために使います。
+///
+/// # 失敗時の動作について
+/// - 関数が失敗した場合、エラーの[span][Error::span]は、呼び出し元のパーサーが解析した範囲を指します。
+/// - 失敗した場合、入力は**解析前の状態に巻き戻されて**返されます。
+pub trait MapRes<I, T> {
+    /// パーサーの結果に、失敗する可能性のある関数を適用する
+    ///
+    /// 関数が[Err]を返した場合、[kind][Error::kind]が [Custom][ErrorKind::Custom] のエラーになります。  
+    /// 関数が返すエラーは、[std::error::Error]を実装した型か、文字列を使えます。
+    ///
+    /// # Example
+    /// ```rust
+    /// use my_parser_project::parser::{combinator::MapRes, str_parser::int, ErrorKind};
+    ///
+    /// let port = int::<u32>.map_res(|n| match n {
+    ///     1..=65535 => Ok(n as u16),
+    ///     _ => Err("port out of range"),
+    /// });
+    /// assert_eq!(port("8080"), ("", Ok(8080)));
+    ///
+    /// let src = "99999";
+    /// let (rest, result) = port(src);
+    /// let err = result.unwrap_err();
+    /// assert!(matches!(err.kind(), ErrorKind::Custom(_)));
+    /// assert_eq!(err.to_string(), "port out of range");
+    /// assert_eq!(err.span().range(&src), 0..5);
+    /// assert_eq!(rest, src);
+    /// ```
+    fn map_res<U, E>(
+        self,
+        f: impl Fn(T) -> Result<U, E> + Clone,
+    ) -> impl Parser<I, Result<U, Error>>
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>;
+
+    /// パーサーの結果が条件を満たすか確認する
+    ///
+    /// 条件を満たさなかった場合、[kind][Error::kind]が [VerifyError][ErrorKind::VerifyError] のエラーになります。
+    ///
+    /// # Example
+    /// ```rust
+    /// use my_parser_project::parser::{combinator::MapRes, str_parser::take_while1};
+    ///
+    /// // 先頭が大文字の単語
+    /// let name = take_while1(|c| c.is_alphabetic()).verify(|s: &&str| s.starts_with(char::is_uppercase));
+    /// assert_eq!(name("Alice!"), ("!", Ok("Alice")));
+    /// assert!(name("bob").1.is_err());
+    /// ```
+    fn verify(self, f: impl Fn(&T) -> bool + Clone) -> impl Parser<I, Result<T, Error>>;
+}
+
+// 実装
+impl<I, T, P> MapRes<I, T> for P
+where
+    I: Input,
+    P: Parser<I, Result<T, Error>>,
+{
+    fn map_res<U, E>(
+        self,
+        f: impl Fn(T) -> Result<U, E> + Clone,
+    ) -> impl Parser<I, Result<U, Error>>
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        move |i: I| match self(i.clone()) {
+            (rest, Ok(t)) => match f(t) {
+                Ok(u) => (rest, Ok(u)),
+                Err(e) => {
+                    let err = Error::new(ErrorKind::Custom(e.into()), Span::new(&i, &rest));
+                    (i, Err(err))
+                }
+            },
+            (rest, Err(e)) => (rest, Err(e)),
+        }
+    }
+
+    fn verify(self, f: impl Fn(&T) -> bool + Clone) -> impl Parser<I, Result<T, Error>> {
+        move |i: I| match self(i.clone()) {
+            (rest, Ok(t)) if f(&t) => (rest, Ok(t)),
+            (rest, Ok(_)) => {
+                let err = Error::new(ErrorKind::VerifyError, Span::new(&i, &rest));
+                (i, Err(err))
+            }
+            (rest, Err(e)) => (rest, Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // std::error::Errorを実装した型をそのまま返せる
+    #[test]
+    fn map_res_std_error_test() {
+        use std::error::Error as _;
+        let src = "300";
+        let parser = str_parser::num.map_res(u8::try_from);
+        let (rest, result) = parser(src);
+        let err = result.unwrap_err();
+        // 元のエラーをたどれる
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            u8::try_from(300u64).unwrap_err().to_string()
+        );
+        assert_eq!(rest, src);
+        assert_eq!(parser("200"), ("", Ok(200)));
+    }
+
+    // 元のパーサーが失敗した場合は、そのエラーを返す
+    #[test]
+    fn map_res_inner_failure_test() {
+        let parser = str_parser::num.map_res(|n| Ok::<_, String>(n + 1));
+        let (_, result) = parser("x");
+        assert!(matches!(
+            result.unwrap_err().kind(),
+            ErrorKind::ParseNumError(_)
+        ));
+    }
+
+    // 条件を満たさない場合は巻き戻される
+    #[test]
+    fn verify_failure_test() {
+        let src = "13;";
+        let even = str_parser::num.verify(|n| n % 2 == 0);
+        let (rest, result) = even(src);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::VerifyError);
+        assert_eq!(err.span().range(&src), 0..2);
+        assert_eq!(rest, src);
+    }
+
+    // Customは表示した文字列で比較される
+    #[test]
+    fn custom_eq_test() {
+        let a = ErrorKind::Custom("out of range".into());
+        let b = ErrorKind::Custom("out of range".into());
+        assert_eq!(a, b);
+        assert_ne!(a, ErrorKind::Custom("other".into()));
+        assert_ne!(ErrorKind::NoProgress, ErrorKind::Expected);
+        assert_eq!(ErrorKind::Incomplete(1), ErrorKind::Incomplete(1));
+        assert_ne!(ErrorKind::Incomplete(1), ErrorKind::Incomplete(2));
+    }
+}
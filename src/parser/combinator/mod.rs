@@ -12,3 +12,45 @@ pub use substitute::*;
 
 mod map;
 pub use map::*;
+
+mod map_res;
+pub use map_res::*;
+
+mod with_span;
+pub use with_span::*;
+
+mod recognize;
+pub use recognize::*;
+
+mod repeat;
+pub use repeat::*;
+
+mod separated;
+pub use separated::*;
+
+mod optional;
+pub use optional::*;
+
+mod alt;
+pub use alt::*;
+
+mod precedence;
+pub use precedence::*;
+
+mod context;
+pub use context::*;
+
+mod cut;
+pub use cut::*;
+
+mod recover;
+pub use recover::*;
+
+mod lookahead;
+pub use lookahead::*;
+
+mod boxed;
+pub use boxed::*;
+
+mod recursive;
+pub use recursive::*;
@@ -0,0 +1,146 @@
+use super::*;
+
+/// 省略可能な要素を解析するメソッドを提供する
+///
+/// 戻り値に`Result<T, Error>`型を用いる[Parser][crate::parser::Parser]で使うことができます。
+///
+/// # 失敗時の動作について
+/// メソッド呼び出し元のパーサーが失敗した場合、入力は**解析前の状態に巻き戻されます**。  
+/// そのため、途中まで解析して失敗したパーサーでも、入力を消費することはありません。  
+/// これらのパーサーは、[致命的なエラー][Error::is_fatal]の場合だけエラーを返します。
+pub trait Optional<I, T> {
+    /// 成功した場合は[Some]、失敗した場合は[None]を返すパーサーを作成します。
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::{char, num}, combinator::*};
+    ///
+    /// // 符号は省略できる
+    /// let parser = char('-').opt().cat(num);
+    ///
+    /// let (_, result) = parser("-12");
+    /// assert_eq!(result, Ok((Some('-'), 12)));
+    ///
+    /// let (_, result) = parser("12");
+    /// assert_eq!(result, Ok((None, 12)));
+    /// ```
+    fn opt(self) -> impl Parser<I, Result<Option<T>, Error>>;
+
+    /// 失敗した場合は、[Default]の値を返すパーサーを作成します。
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::num, combinator::*};
+    ///
+    /// let (rest, n) = num.or_default()("abc");
+    /// assert_eq!(n, Ok(0));
+    /// assert_eq!(rest, "abc");
+    /// ```
+    fn or_default(self) -> impl Parser<I, Result<T, Error>>
+    where
+        T: Default;
+
+    /// 失敗した場合は、指定した値を返すパーサーを作成します。
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::char, combinator::*};
+    ///
+    /// let parser = char('-').or_value('+');
+    ///
+    /// let (_, sign) = parser("10");
+    /// assert_eq!(sign, Ok('+'));
+    /// ```
+    fn or_value(self, v: T) -> impl Parser<I, Result<T, Error>>
+    where
+        T: Clone;
+}
+
+// 実装
+impl<I, T, P> Optional<I, T> for P
+where
+    I: Clone,
+    P: Parser<I, Result<T, Error>>,
+{
+    fn opt(self) -> impl Parser<I, Result<Option<T>, Error>> {
+        move |i: I| match self(i.clone()) {
+            (rest, Ok(t)) => (rest, Ok(Some(t))),
+            // 致命的なエラーは省略できない
+            (_, Err(e)) if e.is_fatal() => (i, Err(e)),
+            // 失敗した場合は巻き戻す
+            (_, Err(_)) => (i, Ok(None)),
+        }
+    }
+
+    fn or_default(self) -> impl Parser<I, Result<T, Error>>
+    where
+        T: Default,
+    {
+        self.opt().map(|r| r.map(Option::unwrap_or_default))
+    }
+
+    fn or_value(self, v: T) -> impl Parser<I, Result<T, Error>>
+    where
+        T: Clone,
+    {
+        self.opt()
+            .map(move |r| r.map(|t| t.unwrap_or_else(|| v.clone())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 成功した場合
+    #[test]
+    fn opt_some_test() {
+        let input = "+1";
+        let parser = str_parser::char('+').opt();
+        let (rest, result) = parser(input);
+        assert_eq!(result, Ok(Some('+')));
+        assert_eq!(rest, "1");
+    }
+
+    // 途中まで解析して失敗した場合も、入力は巻き戻される
+    #[test]
+    fn opt_none_rewind_test() {
+        let input = "+a";
+        let parser = str_parser::char('+').cat(str_parser::num).opt();
+        let (rest, result) = parser(input);
+        assert_eq!(result, Ok(None));
+        assert_eq!(rest, "+a");
+    }
+
+    // 失敗した場合はデフォルト値
+    #[test]
+    fn or_default_test() {
+        let input = "x123";
+        let parser = str_parser::num.or_default();
+        let (rest, result) = parser(input);
+        assert_eq!(result, Ok(0));
+        assert_eq!(rest, "x123");
+    }
+
+    // 失敗した場合は指定した値
+    #[test]
+    fn or_value_test() {
+        let input = "x123";
+        let parser = str_parser::num.or_value(42);
+        let (rest, result) = parser(input);
+        assert_eq!(result, Ok(42));
+        assert_eq!(rest, "x123");
+    }
+
+    // 致命的なエラーは省略されない
+    #[test]
+    fn opt_fatal_test() {
+        let input = "+a";
+        let parser = str_parser::char('+').cat(cut(str_parser::num)).opt();
+        let (rest, result) = parser(input);
+        let err = result.unwrap_err();
+        assert!(err.is_fatal());
+        assert_eq!(err.span().range(&input), 1..2);
+        assert_eq!(rest, "+a");
+    }
+}
@@ -0,0 +1,350 @@
+use std::rc::Rc;
+
+use super::*;
+
+// 項のパーサー
+type Atom<'a, I, T> = Box<dyn Fn(I) -> (I, Result<T, Error>) + 'a>;
+// 演算子を解析し、成功したら構文木を組み立てる関数を返すパーサー
+type Unary<'a, I, T> = Box<dyn Fn(I) -> (I, Result<Box<dyn FnOnce(T) -> T + 'a>, Error>) + 'a>;
+type Binary<'a, I, T> = Box<dyn Fn(I) -> (I, Result<Box<dyn FnOnce(T, T) -> T + 'a>, Error>) + 'a>;
+
+/// 二項演算子の結合性
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Assoc {
+    /// 左結合 (`a - b - c` は `(a - b) - c`)
+    Left,
+    /// 右結合 (`a ^ b ^ c` は `a ^ (b ^ c)`)
+    Right,
+}
+
+/// 演算子の優先順位を使って式をパースする
+///
+/// 項となるパーサーを元に、演算子の表を組み立てる[Precedence]を作成します。  
+/// 演算子を追加したら、[build][Precedence::build]で[Parser][crate::parser::Parser]を作成します。
+///
+/// # 優先順位について
+/// - 演算子ごとに優先順位を指定します。値が大きいほど強く結合します。
+/// - 前置演算子は、自分より優先順位の高い演算子を含む部分に適用されます。
+///   (`-` を `^` より低くすると、 `-2^2` は `-(2^2)` になります)
+/// - 同じ入力に複数の演算子が一致する場合は、先に追加したものが優先されます。
+/// - 入力を消費しなかった演算子は、一致しなかったものとして扱います。
+///
+/// # 失敗時の動作について
+/// - 項が見つからなかった場合は、項と前置演算子のエラーを[Error::merge]でまとめて返します。
+/// - 二項演算子の右側で失敗した場合は、そのエラーを返します。
+/// - 演算子のパーサーが[致命的なエラー][Error::is_fatal]を返した場合は、一致しなかったものとして扱わずに、そのエラーを返します。
+/// - 失敗した場合、入力は**解析前の状態に巻き戻されて**返されます。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::{
+///     combinator::{precedence, Assoc, ConcatUncheck},
+///     str_parser::{char, string, take_while1, trimer},
+/// };
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Filter {
+///     Var(String),
+///     Not(Box<Filter>),
+///     And(Box<Filter>, Box<Filter>),
+///     Or(Box<Filter>, Box<Filter>),
+/// }
+///
+/// let var = trimer.cat_b_uncheck(take_while1(|c| c.is_alphabetic()));
+/// let parser = precedence(move |i| {
+///     let (rest, name) = var(i);
+///     (rest, name.map(|s| Filter::Var(s.to_string())))
+/// })
+/// .prefix(3, trimer.cat_b_uncheck(char('!')), |_, x| Filter::Not(Box::new(x)))
+/// .infix(2, Assoc::Left, trimer.cat_b_uncheck(string("&&".to_string())), |l, _, r| {
+///     Filter::And(Box::new(l), Box::new(r))
+/// })
+/// .infix(1, Assoc::Left, trimer.cat_b_uncheck(string("||".to_string())), |l, _, r| {
+///     Filter::Or(Box::new(l), Box::new(r))
+/// })
+/// .build();
+///
+/// let var = |s: &str| Box::new(Filter::Var(s.to_string()));
+/// let (_, result) = parser("a && b || !c");
+/// assert_eq!(
+///     result,
+///     Ok(Filter::Or(
+///         Box::new(Filter::And(var("a"), var("b"))),
+///         Box::new(Filter::Not(var("c"))),
+///     ))
+/// );
+/// ```
+pub fn precedence<'a, I, T>(atom: impl Parser<I, Result<T, Error>> + 'a) -> Precedence<'a, I, T> {
+    Precedence {
+        atom: Box::new(atom),
+        prefix: Vec::new(),
+        infix: Vec::new(),
+        postfix: Vec::new(),
+    }
+}
+
+/// 演算子の表
+///
+/// [precedence]で作成します。
+pub struct Precedence<'a, I, T> {
+    atom: Atom<'a, I, T>,
+    // (右側の結合力, 演算子)
+    prefix: Vec<(u32, Unary<'a, I, T>)>,
+    // (左側の結合力, 右側の結合力, 演算子)
+    infix: Vec<(u32, u32, Binary<'a, I, T>)>,
+    // (左側の結合力, 演算子)
+    postfix: Vec<(u32, Unary<'a, I, T>)>,
+}
+
+impl<'a, I, T> Precedence<'a, I, T>
+where
+    I: Input + 'a,
+    T: 'a,
+{
+    /// 前置演算子を追加する
+    ///
+    /// fには、演算子の結果と、演算子を適用する項を受け取り、構文木を返す関数を指定します。
+    pub fn prefix<O: 'a>(
+        mut self,
+        prec: u8,
+        op: impl Parser<I, Result<O, Error>> + 'a,
+        f: impl Fn(O, T) -> T + 'a,
+    ) -> Self {
+        let f = Rc::new(f);
+        let op = Box::new(move |i| {
+            let (rest, o) = op(i);
+            let f = f.clone();
+            (
+                rest,
+                o.map(|o| Box::new(move |x| f(o, x)) as Box<dyn FnOnce(T) -> T>),
+            )
+        });
+        self.prefix.push((u32::from(prec) * 2 + 1, op));
+        self
+    }
+    /// 二項演算子を追加する
+    ///
+    /// fには、左側の項、演算子の結果、右側の項を受け取り、構文木を返す関数を指定します。
+    pub fn infix<O: 'a>(
+        mut self,
+        prec: u8,
+        assoc: Assoc,
+        op: impl Parser<I, Result<O, Error>> + 'a,
+        f: impl Fn(T, O, T) -> T + 'a,
+    ) -> Self {
+        let f = Rc::new(f);
+        let op = Box::new(move |i| {
+            let (rest, o) = op(i);
+            let f = f.clone();
+            let o = o.map(|o| Box::new(move |l, r| f(l, o, r)) as Box<dyn FnOnce(T, T) -> T>);
+            (rest, o)
+        });
+        let bp = u32::from(prec) * 2;
+        match assoc {
+            Assoc::Left => self.infix.push((bp, bp + 1, op)),
+            Assoc::Right => self.infix.push((bp + 1, bp, op)),
+        }
+        self
+    }
+    /// 後置演算子を追加する
+    ///
+    /// fには、演算子を適用する項と、演算子の結果を受け取り、構文木を返す関数を指定します。
+    pub fn postfix<O: 'a>(
+        mut self,
+        prec: u8,
+        op: impl Parser<I, Result<O, Error>> + 'a,
+        f: impl Fn(T, O) -> T + 'a,
+    ) -> Self {
+        let f = Rc::new(f);
+        let op = Box::new(move |i| {
+            let (rest, o) = op(i);
+            let f = f.clone();
+            (
+                rest,
+                o.map(|o| Box::new(move |x| f(x, o)) as Box<dyn FnOnce(T) -> T>),
+            )
+        });
+        self.postfix.push((u32::from(prec) * 2, op));
+        self
+    }
+    /// 演算子の表から[Parser][crate::parser::Parser]を作成する
+    pub fn build(self) -> impl Parser<I, Result<T, Error>> + 'a {
+        let table = Rc::new(self);
+        move |i| table.parse(i, 0)
+    }
+
+    // min_bpより強く結合する演算子だけを読み進める
+    fn parse(&self, i: I, min_bp: u32) -> (I, Result<T, Error>) {
+        let (mut rest, mut lhs) = match self.operand(i.clone()) {
+            (rest, Ok(lhs)) => (rest, lhs),
+            (_, Err(e)) => return (i, Err(e)),
+        };
+        loop {
+            // 後置演算子
+            let postfix = self.postfix.iter().find_map(|(bp, op)| {
+                consumed(&rest, op.as_ref())
+                    .transpose()
+                    .map(|r| r.map(|(after, f)| (*bp, after, f)))
+            });
+            if let Some(postfix) = postfix {
+                let (bp, after, f) = match postfix {
+                    Ok(postfix) => postfix,
+                    Err(e) => return (i, Err(e)),
+                };
+                if bp < min_bp {
+                    break;
+                }
+                lhs = f(lhs);
+                rest = after;
+                continue;
+            }
+            // 二項演算子
+            let infix = self.infix.iter().find_map(|(l_bp, r_bp, op)| {
+                consumed(&rest, op.as_ref())
+                    .transpose()
+                    .map(|r| r.map(|(after, f)| (*l_bp, *r_bp, after, f)))
+            });
+            if let Some(infix) = infix {
+                let (l_bp, r_bp, after, f) = match infix {
+                    Ok(infix) => infix,
+                    Err(e) => return (i, Err(e)),
+                };
+                if l_bp < min_bp {
+                    break;
+                }
+                match self.parse(after, r_bp) {
+                    (after, Ok(rhs)) => {
+                        lhs = f(lhs, rhs);
+                        rest = after;
+                    }
+                    (_, Err(e)) => return (i, Err(e)),
+                }
+                continue;
+            }
+            break;
+        }
+        (rest, Ok(lhs))
+    }
+
+    // 前置演算子を適用した項を解析する
+    fn operand(&self, i: I) -> (I, Result<T, Error>) {
+        let mut errors = Vec::new();
+        for (bp, op) in &self.prefix {
+            match op(i.clone()) {
+                (after, Ok(f)) if after.input_len() < i.input_len() => {
+                    return match self.parse(after, *bp) {
+                        (rest, Ok(x)) => (rest, Ok(f(x))),
+                        (_, Err(e)) => (i, Err(e)),
+                    };
+                }
+                // 入力を消費しなかった場合は、一致しなかったものとして扱う
+                (_, Ok(_)) => {}
+                (_, Err(e)) if e.is_fatal() => return (i, Err(e)),
+                (_, Err(e)) => errors.push(e),
+            }
+        }
+        match (self.atom)(i.clone()) {
+            (rest, Ok(x)) => (rest, Ok(x)),
+            (_, Err(e)) => (i, Err(errors.into_iter().fold(e, Error::merge))),
+        }
+    }
+}
+
+// 演算子を試し、入力を消費して成功した場合だけ結果を返す
+// 致命的なエラーは、一致しなかったものとして扱わずに返す
+fn consumed<I: Input, R>(
+    i: &I,
+    op: &dyn Fn(I) -> (I, Result<R, Error>),
+) -> Result<Option<(I, R)>, Error> {
+    match op(i.clone()) {
+        (after, Ok(r)) if after.input_len() < i.input_len() => Ok(Some((after, r))),
+        (_, Err(e)) if e.is_fatal() => Err(e),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::str_parser::{char, int};
+
+    // 式を文字列に戻して、結合の仕方を確認する
+    fn parser<'a>() -> impl Parser<&'a str, Result<String, Error>> {
+        precedence(int::<i64>.map(|r| r.map(|n| n.to_string())))
+            .prefix(3, char('-'), |_, x| format!("(-{})", x))
+            .infix(1, Assoc::Left, char('+'), |l, _, r| {
+                format!("({}+{})", l, r)
+            })
+            .infix(2, Assoc::Left, char('*'), |l, _, r| {
+                format!("({}*{})", l, r)
+            })
+            .infix(4, Assoc::Right, char('^'), |l, _, r| {
+                format!("({}^{})", l, r)
+            })
+            .postfix(5, char('!'), |x, _| format!("({}!)", x))
+            .build()
+    }
+
+    // 優先順位と結合性
+    #[test]
+    fn assoc_test() {
+        assert_eq!(parser()("1+2+3*4"), ("", Ok("((1+2)+(3*4))".into())));
+        assert_eq!(parser()("2^3^2"), ("", Ok("(2^(3^2))".into())));
+    }
+
+    // 前置演算子と後置演算子
+    #[test]
+    fn unary_test() {
+        assert_eq!(parser()("-2^2"), ("", Ok("(-(2^2))".into())));
+        assert_eq!(parser()("-2*3"), ("", Ok("((-2)*3)".into())));
+        assert_eq!(parser()("--3!"), ("", Ok("(-(-(3!)))".into())));
+        assert_eq!(parser()("2^3!"), ("", Ok("(2^(3!))".into())));
+    }
+
+    // 演算子の後ろに項がない場合は、巻き戻される
+    #[test]
+    fn error_test() {
+        let src = "1+2*";
+        let (rest, result) = parser()(src);
+        let err = result.unwrap_err();
+        // 項と前置演算子のエラーがまとめられる
+        assert_eq!(err.kind(), &ErrorKind::Expected);
+        assert_eq!(err.expected(), ["integer", "'-'"]);
+        assert_eq!(err.span().range(&src), 4..4);
+        assert_eq!(rest, src);
+    }
+
+    // 演算子が致命的なエラーを返した場合は、そこで打ち切る
+    #[test]
+    fn cut_test() {
+        use crate::parser::combinator::cut;
+
+        // `**`は使えないので、`*`の後ろに`*`が続いたらエラーにする
+        let times = char('*').cat_a(cut(not(char('*'))));
+        let parser = precedence(int::<i64>)
+            .infix(1, Assoc::Left, times, |l, _, r| l * r)
+            .postfix(2, char('!').cat_a(cut(char('!'))), |x, _| x)
+            .build();
+        let src = "2**3";
+        let (rest, result) = parser(src);
+        let err = result.unwrap_err();
+        assert!(err.is_fatal());
+        assert_eq!(err.kind(), &ErrorKind::Unexpected);
+        assert_eq!(err.span().range(&src), 2..3);
+        assert_eq!(rest, src);
+
+        // 後置演算子も同じ
+        let src = "2!*3";
+        let (rest, result) = parser(src);
+        let err = result.unwrap_err();
+        assert!(err.is_fatal());
+        assert_eq!(err.span().range(&src), 2..3);
+        assert_eq!(rest, src);
+    }
+
+    // 式の後ろの部分は残る
+    #[test]
+    fn rest_test() {
+        assert_eq!(parser()("1+2)"), (")", Ok("(1+2)".into())));
+    }
+}
@@ -0,0 +1,101 @@
+use super::*;
+
+/// パーサーが解析した部分の入力を取得するメソッドを提供する
+///
+/// 戻り値に[Result]型を用いる[Parser][crate::parser::Parser]で使うことができます。  
+/// [`&str`][str]や[`&[u8]`][slice]など、[Input]を実装した全ての入力で使えます。
+pub trait Recognize<I, T, E> {
+    /// 解析に成功した場合、結果の代わりに解析した部分の入力を返すパーサーを作成します。
+    ///
+    /// 構造を確認しつつ、元の文字列をそのまま使いたい場合に便利です。
+    ///
+    /// ## 結果とエラー
+    /// - メソッド呼び出し元のパーサーが[Ok]だった場合は、解析した部分の入力が返されます
+    /// - メソッド呼び出し元のパーサーが[Err]だった場合は、そのままエラーが返されます
+    ///
+    /// ## Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::{char, num}, combinator::*};
+    ///
+    /// // "1.2.3"の形式を確認し、文字列のまま取り出す
+    /// let version = num.cat(char('.')).cat(num).cat(char('.')).cat(num).recognize();
+    /// let (rest, result) = version("1.2.3 beta");
+    /// assert_eq!(result, Ok("1.2.3"));
+    /// assert_eq!(rest, " beta");
+    /// ```
+    fn recognize(self) -> impl Parser<I, Result<I, E>>;
+
+    /// 解析に成功した場合、解析した部分の入力と結果の両方を返すパーサーを作成します。
+    ///
+    /// ## 結果とエラー
+    /// - メソッド呼び出し元のパーサーが[Ok]だった場合は、`(解析した部分, 結果)`のタプルが返されます
+    /// - メソッド呼び出し元のパーサーが[Err]だった場合は、そのままエラーが返されます
+    ///
+    /// ## Example
+    /// ```
+    /// use my_parser_project::parser::{bytes_parser::be_u16, combinator::*};
+    ///
+    /// let input: &[u8] = &[0x01, 0x02, 0xff];
+    /// let (rest, result) = be_u16.consumed()(input);
+    /// assert_eq!(result, Ok((&input[..2], 0x0102)));
+    /// assert_eq!(rest, &[0xff]);
+    /// ```
+    fn consumed(self) -> impl Parser<I, Result<(I, T), E>>;
+}
+
+// 実装
+impl<I, T, E, P> Recognize<I, T, E> for P
+where
+    I: Input,
+    P: Parser<I, Result<T, E>>,
+{
+    fn recognize(self) -> impl Parser<I, Result<I, E>> {
+        self.consumed().map(|r| r.map(|(consumed, _)| consumed))
+    }
+
+    fn consumed(self) -> impl Parser<I, Result<(I, T), E>> {
+        move |i: I| match self(i.clone()) {
+            // 解析前の入力から、解析後の入力の長さを除いた部分が、解析した部分になる
+            (rest, Ok(t)) => {
+                let (consumed, _) = i.split_at_offset(rest.offset_from(&i));
+                (rest, Ok((consumed, t)))
+            }
+            (rest, Err(e)) => (rest, Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 文字列の一部を取り出す
+    #[test]
+    fn recognize_str_test() {
+        let parser = str_parser::char('-')
+            .opt()
+            .cat(str_parser::take_while1(|c| c.is_ascii_digit()))
+            .recognize();
+        assert_eq!(parser("-42;"), (";", Ok("-42")));
+        assert_eq!(parser("42;"), (";", Ok("42")));
+    }
+
+    // バイト列の一部を取り出す
+    #[test]
+    fn recognize_bytes_test() {
+        let base: &[u8] = b"GET /path";
+        let parser = bytes_parser::tag(b"GET")
+            .cat(bytes_parser::byte(b' '))
+            .recognize();
+        assert_eq!(parser(base), (&b"/path"[..], Ok(&b"GET "[..])));
+    }
+
+    // 失敗した場合はそのままエラーが返される
+    #[test]
+    fn consumed_failure_test() {
+        let src = "x";
+        let (rest, result) = str_parser::num.consumed()(src);
+        assert!(result.is_err());
+        assert_eq!(rest, "x");
+    }
+}
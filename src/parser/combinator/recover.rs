@@ -0,0 +1,209 @@
+use super::*;
+
+/// エラーから回復しながら解析した結果
+///
+/// [recover_with][Recover::recover_with]で作成されます。  
+/// 解析できた部分の結果と、途中で記録されたエラーを持ちます。
+#[derive(Debug, PartialEq)]
+pub struct Recovered<T> {
+    /// 解析できた部分の結果
+    pub value: T,
+    /// 回復したエラーの一覧
+    pub errors: Vec<Error>,
+}
+
+impl<T> Recovered<Vec<T>> {
+    /// 結果とエラーが空の状態を作成
+    ///
+    /// [fold_many0][Repeat::fold_many0]の初期値として使います。
+    pub fn new() -> Self {
+        Self {
+            value: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+    /// [recover_with][Recover::recover_with]の結果を追加する
+    ///
+    /// [fold_many0][Repeat::fold_many0]で、結果をまとめるために使います。
+    pub fn push(mut self, r: Recovered<Option<T>>) -> Self {
+        self.value.extend(r.value);
+        self.errors.extend(r.errors);
+        self
+    }
+}
+
+impl<T> Default for Recovered<Vec<T>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// エラーから回復するメソッドを提供する
+///
+/// 戻り値に[Result]型を用いる[Parser][crate::parser::Parser]で使うことができます。
+pub trait Recover<I, T> {
+    /// 失敗した場合に、エラーを記録して解析を続けられるようにする
+    ///
+    /// 呼び出し元のパーサーが失敗した場合、エラーを記録し、
+    /// 引数のパーサーで入力を読み飛ばして、次に解析できる位置まで進めます。  
+    /// 読み飛ばしには、[skip_until]を使うと便利です。
+    ///
+    /// ## 結果とエラー
+    /// - 成功した場合は、結果が`Some`の[Recovered]が返されます。
+    /// - 失敗して回復した場合は、結果が`None`で、エラーが記録された[Recovered]が返されます。
+    /// - 読み飛ばしのパーサーが失敗したり、入力を消費しなかった場合は回復できず、
+    ///   元のエラーが返されます。入力は巻き戻されます。
+    ///
+    /// ## Example
+    /// 繰り返しと組み合わせることで、全ての間違いをまとめて報告できます。
+    /// ```
+    /// use my_parser_project::parser::{
+    ///     combinator::*,
+    ///     str_parser::{char, int, take_while1},
+    /// };
+    ///
+    /// let src = "a=1;b=?;c=3;";
+    /// let statement = take_while1(|c| c.is_alphabetic())
+    ///     .cat_a(char('='))
+    ///     .cat(int::<i64>)
+    ///     .cat_a(char(';'));
+    /// let parser = statement
+    ///     .recover_with(skip_until(char(';')))
+    ///     .fold_many0(Recovered::new, Recovered::push);
+    ///
+    /// let (rest, result) = parser(src);
+    /// let recovered = result.unwrap();
+    /// assert_eq!(recovered.value, [("a", 1), ("c", 3)]);
+    /// assert_eq!(recovered.errors.len(), 1);
+    /// assert_eq!(recovered.errors[0].span().range(&src), 6..7);
+    /// assert_eq!(rest, "");
+    /// ```
+    fn recover_with<S>(
+        self,
+        skip: impl Parser<I, Result<S, Error>>,
+    ) -> impl Parser<I, Result<Recovered<Option<T>>, Error>>;
+}
+
+// 実装
+impl<I, T, P> Recover<I, T> for P
+where
+    I: Input,
+    P: Parser<I, Result<T, Error>>,
+{
+    fn recover_with<S>(
+        self,
+        skip: impl Parser<I, Result<S, Error>>,
+    ) -> impl Parser<I, Result<Recovered<Option<T>>, Error>> {
+        move |i: I| match self(i.clone()) {
+            (rest, Ok(t)) => {
+                let r = Recovered {
+                    value: Some(t),
+                    errors: Vec::new(),
+                };
+                (rest, Ok(r))
+            }
+            // 読み飛ばしが入力を消費した場合だけ回復する
+            (_, Err(e)) => match skip(i.clone()) {
+                (rest, Ok(_)) if rest.input_len() < i.input_len() => {
+                    let r = Recovered {
+                        value: None,
+                        errors: vec![e],
+                    };
+                    (rest, Ok(r))
+                }
+                _ => (i, Err(e)),
+            },
+        }
+    }
+}
+
+/// 同期用のパーサーが一致するまで読み飛ばす
+///
+/// 引数のパーサーが一致する位置まで入力を読み飛ばし、一致した部分も消費する [Parser][crate::parser::Parser] を**作成**します。  
+/// [recover_with][Recover::recover_with]で、`;`などの区切りまで進めるために使います。
+///
+/// ## 結果
+/// - 読み飛ばした部分(同期用のパーサーが一致した部分は含まない)が返されます。
+/// - 最後まで一致しなかった場合は、入力の終わりまで読み飛ばします。このパーサーはエラーを返すことはありません。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::{combinator::skip_until, str_parser::char};
+///
+/// let parser = skip_until(char(';'));
+/// assert_eq!(parser("x = ?; y = 1;"), (" y = 1;", Ok("x = ?")));
+/// assert_eq!(parser("x = ?"), ("", Ok("x = ?")));
+/// ```
+pub fn skip_until<I, S>(sync: impl Parser<I, Result<S, Error>>) -> impl Parser<I, Result<I, Error>>
+where
+    I: Input,
+{
+    move |i: I| {
+        let offsets = i.iter_elements().map(|(p, _)| p);
+        for p in offsets {
+            let (skipped, rest) = i.split_at_offset(p);
+            if let (rest, Ok(_)) = sync(rest) {
+                return (rest, Ok(skipped));
+            }
+        }
+        let (skipped, rest) = i.split_at_offset(i.input_len());
+        (rest, Ok(skipped))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::str_parser::{char, int, take_while1};
+
+    fn statement<'a>() -> impl Parser<&'a str, Result<(&'a str, i64), Error>> {
+        str_parser::trimer.cat_b_uncheck(
+            take_while1(|c| c.is_alphabetic())
+                .cat_a(char('='))
+                .cat(int::<i64>)
+                .cat_a(char(';')),
+        )
+    }
+
+    // 全てのエラーを記録し、解析できた部分を返す
+    #[test]
+    fn collect_errors_test() {
+        let src = "a=1; b=; c=3; d=x; e=5";
+        let parser = statement()
+            .recover_with(skip_until(char(';')))
+            .fold_many0(Recovered::new, Recovered::push);
+        let (rest, result) = parser(src);
+        let recovered = result.unwrap();
+        assert_eq!(recovered.value, [("a", 1), ("c", 3)]);
+        let ranges: Vec<_> = recovered
+            .errors
+            .iter()
+            .map(|e| e.span().range(&src))
+            .collect();
+        // 'b='の後ろ、'd='の後ろ、'e=5'の後ろ
+        assert_eq!(ranges, [7..8, 16..17, 22..22]);
+        assert_eq!(rest, "");
+    }
+
+    // 成功した場合はエラーがない
+    #[test]
+    fn success_test() {
+        let (rest, result) = statement().recover_with(skip_until(char(';')))("a=1;");
+        assert_eq!(
+            result,
+            Ok(Recovered {
+                value: Some(("a", 1)),
+                errors: vec![],
+            })
+        );
+        assert_eq!(rest, "");
+    }
+
+    // 読み飛ばせない場合は回復できない
+    #[test]
+    fn unrecoverable_test() {
+        let (rest, result) = statement().recover_with(skip_until(char(';')))("");
+        assert!(result.is_err());
+        assert_eq!(rest, "");
+    }
+}
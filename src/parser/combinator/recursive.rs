@@ -0,0 +1,112 @@
+use std::{cell::OnceCell, rc::Rc};
+
+use super::*;
+
+/// 自分自身を参照するパーサーを作成する
+///
+/// 括弧の入れ子や、配列の中の配列など、再帰的な文法を解析する [Parser][crate::parser::Parser] を**作成**します。  
+/// fには、作成中のパーサー自身を[BoxedParser]として受け取り、パーサーを返す関数を指定します。  
+/// 受け取ったパーサーは、他のパーサーと同じように組み合わせられます。
+///
+/// # 注意
+/// - 受け取ったパーサーは、fの中で**実行してはいけません**。fが返るまで、パーサーは完成していないためです。
+/// - 受け取ったパーサーは、作成されたパーサーが残っている間だけ使えます。
+///   作成されたパーサー(またはその複製)を全て破棄した後に実行するとパニックします。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::{
+///     combinator::{alt, recursive, Concat, Map, Separated, Trailing},
+///     str_parser::{char, int},
+/// };
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Value {
+///     Int(i64),
+///     Array(Vec<Value>),
+/// }
+///
+/// // value = int | '[' value (',' value)* ']'
+/// let value = recursive(|value| {
+///     let array = char('[')
+///         .cat_b(value.sep_by(char(','), Trailing::Forbid))
+///         .cat_a(char(']'))
+///         .map(|r| r.map(Value::Array));
+///     alt((int.map(|r| r.map(Value::Int)), array))
+/// });
+///
+/// let (_, result) = value("[1,[2,[]]]");
+/// assert_eq!(
+///     result,
+///     Ok(Value::Array(vec![
+///         Value::Int(1),
+///         Value::Array(vec![Value::Int(2), Value::Array(vec![])]),
+///     ]))
+/// );
+/// ```
+pub fn recursive<'a, I, R, P>(f: impl FnOnce(BoxedParser<'a, I, R>) -> P) -> impl Parser<I, R> + 'a
+where
+    I: 'a,
+    R: 'a,
+    P: Parser<I, R> + 'a,
+{
+    let cell: Rc<OnceCell<P>> = Rc::new(OnceCell::new());
+    // 自身への参照は弱参照にして、循環参照にならないようにする
+    let weak = Rc::downgrade(&cell);
+    let self_ref: BoxedParser<'a, I, R> = Box::new(move |i| {
+        let cell = weak
+            .upgrade()
+            .expect("recursive parser was used after it was dropped");
+        let p = cell
+            .get()
+            .expect("recursive parser was used before it was defined");
+        p(i)
+    });
+    if cell.set(f(self_ref)).is_err() {
+        unreachable!("recursive parser is defined only once");
+    }
+    move |i| (cell.get().expect("recursive parser is defined"))(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::str_parser::char;
+
+    // 括弧の深さを数える
+    fn depth<'a>() -> impl Parser<&'a str, Result<usize, Error>> {
+        recursive(|depth| {
+            char('(')
+                .cat_b(depth.or_default())
+                .cat_a(char(')'))
+                .map(|r| r.map(|d| d + 1))
+        })
+    }
+
+    // 入れ子を解析できる
+    #[test]
+    fn nested_test() {
+        assert_eq!(depth()("((()))"), ("", Ok(3)));
+        assert_eq!(depth()("()()"), ("()", Ok(1)));
+    }
+
+    // 失敗した場合は巻き戻される
+    #[test]
+    fn failure_test() {
+        let src = "(()";
+        let (rest, result) = depth()(src);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::ParseCharError);
+        assert_eq!(err.span().range(&src), 3..3);
+        assert_eq!(rest, src);
+    }
+
+    // 複製しても同じように使える
+    #[test]
+    fn clone_test() {
+        let parser = depth();
+        let cloned = parser.clone();
+        drop(parser);
+        assert_eq!(cloned("(())"), ("", Ok(2)));
+    }
+}
@@ -0,0 +1,323 @@
+use super::*;
+
+/// パーサーを繰り返し適用するメソッドを提供する
+///
+/// 戻り値に`Result<T, Error>`型を用いる[Parser][crate::parser::Parser]で使うことができます。
+///
+/// # 失敗時の動作について
+/// - 繰り返しの途中でパーサーが失敗した場合、その回の解析はなかったことになります。
+/// - 必要な回数に届かずに失敗した場合、入力は**解析前の状態に巻き戻されて**返されます。
+/// - [致命的なエラー][Error::is_fatal]が返された場合は、回数に関わらず繰り返しを中断し、そのエラーを返します。
+///
+/// # 無限ループの防止について
+/// 入力を消費せずに成功するパーサー(例: `many0`したパーサー)を繰り返すと、永遠に終わりません。  
+/// そのため、`many`系と`fold`系のメソッドは、パーサーが入力を消費せずに成功した時点で
+/// [NoProgress][crate::parser::ErrorKind::NoProgress]エラーを返します。
+pub trait Repeat<I, T> {
+    /// 0回以上繰り返し、結果を[Vec]にまとめる
+    ///
+    /// 一度も成功しなくてもエラーにはならず、空の[Vec]が返されます。
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::{num, trimer}, combinator::*};
+    ///
+    /// let parser = trimer.cat_b_uncheck(num).many0();
+    ///
+    /// let (rest, result) = parser("1 2 3abc");
+    /// assert_eq!(result, Ok(vec![1, 2, 3]));
+    /// assert_eq!(rest, "abc");
+    ///
+    /// let (rest, result) = parser("abc");
+    /// assert_eq!(result, Ok(vec![]));
+    /// assert_eq!(rest, "abc");
+    /// ```
+    fn many0(self) -> impl Parser<I, Result<Vec<T>, Error>>;
+
+    /// 1回以上繰り返し、結果を[Vec]にまとめる
+    ///
+    /// 一度も成功しなかった場合は、最初の失敗のエラーが返されます。
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::char, combinator::*};
+    ///
+    /// let parser = char('a').many1();
+    ///
+    /// let (_, result) = parser("aab");
+    /// assert_eq!(result, Ok(vec!['a', 'a']));
+    ///
+    /// let (_, result) = parser("bab");
+    /// assert!(result.is_err());
+    /// ```
+    fn many1(self) -> impl Parser<I, Result<Vec<T>, Error>>;
+
+    /// ちょうどn回繰り返し、結果を[Vec]にまとめる
+    ///
+    /// n回に届かなかった場合は、失敗した回のエラーが返されます。
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::char, combinator::*};
+    ///
+    /// let parser = char('a').count(2);
+    ///
+    /// let (rest, result) = parser("aaa");
+    /// assert_eq!(result, Ok(vec!['a', 'a']));
+    /// assert_eq!(rest, "a");
+    ///
+    /// let (rest, result) = parser("ab");
+    /// assert!(result.is_err());
+    /// assert_eq!(rest, "ab");
+    /// ```
+    fn count(self, n: usize) -> impl Parser<I, Result<Vec<T>, Error>>;
+
+    /// m回以上、n回以下繰り返し、結果を[Vec]にまとめる
+    ///
+    /// n回成功した時点で繰り返しを終了します。  
+    /// m回に届かなかった場合は、失敗した回のエラーが返されます。
+    ///
+    /// # Panics
+    /// `m > n`の場合はpanicします。
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::char, combinator::*};
+    ///
+    /// let parser = char('a').many_m_n(2, 3);
+    ///
+    /// let (rest, result) = parser("aaaa");
+    /// assert_eq!(result, Ok(vec!['a', 'a', 'a']));
+    /// assert_eq!(rest, "a");
+    ///
+    /// let (_, result) = parser("ab");
+    /// assert!(result.is_err());
+    /// ```
+    fn many_m_n(self, m: usize, n: usize) -> impl Parser<I, Result<Vec<T>, Error>>;
+
+    /// 0回以上繰り返し、結果を関数で畳み込む
+    ///
+    /// `init`で初期値を作成し、成功するたびに`f`で結果を積み上げます。  
+    /// [Vec]を作らずに済むため、合計値などを求める時に便利です。
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::{num, trimer}, combinator::*};
+    ///
+    /// let parser = trimer
+    ///     .cat_b_uncheck(num)
+    ///     .fold_many0(|| 0, |acc, n| acc + n);
+    ///
+    /// let (_, result) = parser("1 2 3");
+    /// assert_eq!(result, Ok(6));
+    /// ```
+    fn fold_many0<Acc>(
+        self,
+        init: impl Fn() -> Acc + Clone,
+        f: impl Fn(Acc, T) -> Acc + Clone,
+    ) -> impl Parser<I, Result<Acc, Error>>;
+
+    /// 1回以上繰り返し、結果を関数で畳み込む
+    ///
+    /// 一度も成功しなかった場合は、最初の失敗のエラーが返されます。
+    fn fold_many1<Acc>(
+        self,
+        init: impl Fn() -> Acc + Clone,
+        f: impl Fn(Acc, T) -> Acc + Clone,
+    ) -> impl Parser<I, Result<Acc, Error>>;
+}
+
+// 実装
+impl<I, T, P> Repeat<I, T> for P
+where
+    I: Input,
+    P: Parser<I, Result<T, Error>>,
+{
+    fn many0(self) -> impl Parser<I, Result<Vec<T>, Error>> {
+        self.many_m_n(0, usize::MAX)
+    }
+
+    fn many1(self) -> impl Parser<I, Result<Vec<T>, Error>> {
+        self.many_m_n(1, usize::MAX)
+    }
+
+    fn count(self, n: usize) -> impl Parser<I, Result<Vec<T>, Error>> {
+        move |i: I| {
+            let mut rest = i.clone();
+            // nは上限でしかないので、入力の長さを超えて確保しない
+            let mut list = Vec::with_capacity(n.min(i.input_len()));
+            // 回数が決まっているので、無限ループの確認はしない
+            for _ in 0..n {
+                match self(rest.clone()) {
+                    (next, Ok(t)) => {
+                        list.push(t);
+                        rest = next;
+                    }
+                    (_, Err(e)) => return (i, Err(e)),
+                }
+            }
+            (rest, Ok(list))
+        }
+    }
+
+    fn many_m_n(self, m: usize, n: usize) -> impl Parser<I, Result<Vec<T>, Error>> {
+        fold_m_n(self, m, n, Vec::new, |mut list, t| {
+            list.push(t);
+            list
+        })
+    }
+
+    fn fold_many0<Acc>(
+        self,
+        init: impl Fn() -> Acc + Clone,
+        f: impl Fn(Acc, T) -> Acc + Clone,
+    ) -> impl Parser<I, Result<Acc, Error>> {
+        fold_m_n(self, 0, usize::MAX, init, f)
+    }
+
+    fn fold_many1<Acc>(
+        self,
+        init: impl Fn() -> Acc + Clone,
+        f: impl Fn(Acc, T) -> Acc + Clone,
+    ) -> impl Parser<I, Result<Acc, Error>> {
+        fold_m_n(self, 1, usize::MAX, init, f)
+    }
+}
+
+// 繰り返し系メソッドの共通実装
+// m回以上、n回以下繰り返し、結果をfで畳み込む
+fn fold_m_n<I, T, Acc>(
+    p: impl Parser<I, Result<T, Error>>,
+    m: usize,
+    n: usize,
+    init: impl Fn() -> Acc + Clone,
+    f: impl Fn(Acc, T) -> Acc + Clone,
+) -> impl Parser<I, Result<Acc, Error>>
+where
+    I: Input,
+{
+    assert!(
+        m <= n,
+        "many_m_n: m({}) must not be greater than n({})",
+        m,
+        n
+    );
+    move |i: I| {
+        let mut rest = i.clone();
+        let mut acc = init();
+        let mut count = 0;
+        while count < n {
+            match p(rest.clone()) {
+                (next, Ok(t)) => {
+                    // 入力が消費されていなければ、無限ループになるので中断する
+                    if next.input_len() == rest.input_len() {
+                        let err = Error::new(ErrorKind::NoProgress, Span::at(&rest));
+                        return (i, Err(err));
+                    }
+                    acc = f(acc, t);
+                    rest = next;
+                    count += 1;
+                }
+                // 必要な回数に届いていないか、致命的なエラーであれば、巻き戻してエラーを返す
+                (_, Err(e)) if count < m || e.is_fatal() => return (i, Err(e)),
+                (_, Err(_)) => break,
+            }
+        }
+        (rest, Ok(acc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 数字の並びをパースする
+    #[test]
+    fn many0_success_test() {
+        let input = "1 2 3;";
+        let parser = str_parser::trimer.cat_b_uncheck(str_parser::num).many0();
+        let (rest, result) = parser(input);
+        assert_eq!(result, Ok(vec![1, 2, 3]));
+        assert_eq!(rest, ";");
+    }
+
+    // 一度も成功しなくても、many0はエラーにならない
+    #[test]
+    fn many0_empty_test() {
+        let input = "abc";
+        let parser = str_parser::num.many0();
+        let (rest, result) = parser(input);
+        assert_eq!(result, Ok(vec![]));
+        assert_eq!(rest, "abc");
+    }
+
+    // many1は一度も成功しないとエラー
+    #[test]
+    fn many1_failure_test() {
+        let input = "abc";
+        let parser = str_parser::num.many1();
+        let (rest, result) = parser(input);
+        assert!(matches!(
+            result.unwrap_err().kind(),
+            ErrorKind::ParseNumError(_)
+        ));
+        assert_eq!(rest, "abc");
+    }
+
+    // 回数が足りない場合は巻き戻される
+    #[test]
+    fn count_failure_test() {
+        let input = "**+";
+        let parser = str_parser::char('*').count(3);
+        let (rest, result) = parser(input);
+        assert_eq!(result.unwrap_err().span().range(&input), 2..3);
+        assert_eq!(rest, "**+");
+    }
+
+    // 回数が入力より大きくても、確保しすぎない
+    #[test]
+    fn count_huge_test() {
+        let input = "aa";
+        let parser = str_parser::char('a').count(usize::MAX);
+        let (rest, result) = parser(input);
+        assert_eq!(result.unwrap_err().span().range(&input), 2..2);
+        assert_eq!(rest, "aa");
+
+        let (rest, result) = str_parser::char('a').count(1 << 40)("");
+        assert!(result.is_err());
+        assert_eq!(rest, "");
+    }
+
+    // 上限に達したら終了する
+    #[test]
+    fn many_m_n_upper_test() {
+        let input = "*****";
+        let parser = str_parser::char('*').many_m_n(1, 3);
+        let (rest, result) = parser(input);
+        assert_eq!(result, Ok(vec!['*'; 3]));
+        assert_eq!(rest, "**");
+    }
+
+    // 入力を消費しないパーサーを繰り返すとエラーになる
+    #[test]
+    fn no_progress_guard_test() {
+        let input = "abc";
+        let parser = str_parser::num.many0().many0();
+        let (rest, result) = parser(input);
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::NoProgress);
+        assert_eq!(rest, "abc");
+    }
+
+    // 畳み込み
+    #[test]
+    fn fold_many1_success_test() {
+        let input = "1+2+3";
+        let parser = str_parser::char('+')
+            .sub_uncheck(str_parser::none)
+            .cat_b_uncheck(str_parser::num)
+            .fold_many1(|| 0, |acc, n| acc + n);
+        let (rest, result) = parser(input);
+        assert_eq!(result, Ok(6));
+        assert_eq!(rest, "");
+    }
+}
@@ -0,0 +1,252 @@
+use super::*;
+
+/// 末尾の区切り文字の扱い
+///
+/// [Separated]の各メソッドで、リストの最後の要素の後ろに区切り文字があった場合の扱いを指定します。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trailing {
+    /// 末尾の区切り文字を許可する (`1,2,3` と `1,2,3,` のどちらも可)
+    Allow,
+    /// 末尾の区切り文字を必須にする (`1,2,3,` のみ可)
+    Require,
+    /// 末尾の区切り文字を禁止する (`1,2,3` のみ可)
+    Forbid,
+}
+
+/// 区切り文字で区切られたリストを解析するメソッドを提供する
+///
+/// 戻り値に`Result<T, Error>`型を用いる[Parser][crate::parser::Parser]を要素のパーサーとして使います。  
+/// 区切り文字のパーサーの結果は破棄されます。
+///
+/// # 失敗時の動作について
+/// - 失敗した場合、入力は**解析前の状態に巻き戻されて**返されます。
+/// - 末尾の区切り文字が[禁止][Trailing::Forbid]されている場合は、
+///   [TrailingSeparator][crate::parser::ErrorKind::TrailingSeparator]エラーが、余った区切り文字を指して返されます。
+/// - 末尾の区切り文字が[必須][Trailing::Require]の場合は、最後の要素の後ろで区切り文字のパーサーのエラーが返されます。
+/// - 要素か区切り文字のパーサーが[致命的なエラー][crate::parser::Error::is_fatal]を返した場合は、そのエラーが返されます。
+pub trait Separated<I, T> {
+    /// 区切り文字で区切られた、0個以上の要素を解析する
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::{char, num}, combinator::*};
+    ///
+    /// let parser = num.sep_by(char(','), Trailing::Forbid);
+    ///
+    /// let (rest, result) = parser("1,2,3;");
+    /// assert_eq!(result, Ok(vec![1, 2, 3]));
+    /// assert_eq!(rest, ";");
+    ///
+    /// // 末尾の区切り文字を指してエラーになる
+    /// let src = "1,2,;";
+    /// let (rest, result) = parser(src);
+    /// assert_eq!(result.unwrap_err().span().range(&src), 3..4);
+    /// assert_eq!(rest, src);
+    /// ```
+    fn sep_by<S>(
+        self,
+        sep: impl Parser<I, Result<S, Error>>,
+        trailing: Trailing,
+    ) -> impl Parser<I, Result<Vec<T>, Error>>;
+
+    /// 区切り文字で区切られた、1個以上の要素を解析する
+    ///
+    /// 要素が一つもなかった場合は、要素のパーサーのエラーが返されます。
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::{char, num}, combinator::*};
+    ///
+    /// let parser = num.sep_by1(char(':'), Trailing::Allow);
+    ///
+    /// let (_, result) = parser("1:2:");
+    /// assert_eq!(result, Ok(vec![1, 2]));
+    ///
+    /// let (_, result) = parser(":");
+    /// assert!(result.is_err());
+    /// ```
+    fn sep_by1<S>(
+        self,
+        sep: impl Parser<I, Result<S, Error>>,
+        trailing: Trailing,
+    ) -> impl Parser<I, Result<Vec<T>, Error>>;
+
+    /// 全ての要素の後ろに区切り文字がある、0個以上の要素を解析する
+    ///
+    /// `sep_by(sep, Trailing::Require)`と同じです。  
+    /// `;`で終わる文の並びなどに使います。
+    ///
+    /// # Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::{char, num}, combinator::*};
+    ///
+    /// let parser = num.terminated_list(char(';'));
+    ///
+    /// let (_, result) = parser("1;2;");
+    /// assert_eq!(result, Ok(vec![1, 2]));
+    ///
+    /// // 最後の';'がない
+    /// let (_, result) = parser("1;2");
+    /// assert_eq!(result.unwrap_err().expected(), ["';'"]);
+    /// ```
+    fn terminated_list<S>(
+        self,
+        sep: impl Parser<I, Result<S, Error>>,
+    ) -> impl Parser<I, Result<Vec<T>, Error>>;
+}
+
+// 実装
+impl<I, T, P> Separated<I, T> for P
+where
+    I: Input,
+    P: Parser<I, Result<T, Error>>,
+{
+    fn sep_by<S>(
+        self,
+        sep: impl Parser<I, Result<S, Error>>,
+        trailing: Trailing,
+    ) -> impl Parser<I, Result<Vec<T>, Error>> {
+        separated(self, sep, trailing, 0)
+    }
+
+    fn sep_by1<S>(
+        self,
+        sep: impl Parser<I, Result<S, Error>>,
+        trailing: Trailing,
+    ) -> impl Parser<I, Result<Vec<T>, Error>> {
+        separated(self, sep, trailing, 1)
+    }
+
+    fn terminated_list<S>(
+        self,
+        sep: impl Parser<I, Result<S, Error>>,
+    ) -> impl Parser<I, Result<Vec<T>, Error>> {
+        separated(self, sep, Trailing::Require, 0)
+    }
+}
+
+// 区切り文字系メソッドの共通実装
+// min個以上の要素を解析する
+fn separated<I, T, S>(
+    item: impl Parser<I, Result<T, Error>>,
+    sep: impl Parser<I, Result<S, Error>>,
+    trailing: Trailing,
+    min: usize,
+) -> impl Parser<I, Result<Vec<T>, Error>>
+where
+    I: Input,
+{
+    move |i: I| {
+        let mut list = Vec::new();
+        // 最初の要素
+        let mut rest = match item(i.clone()) {
+            (rest, Ok(t)) => {
+                list.push(t);
+                rest
+            }
+            (_, Err(e)) if min > 0 || e.is_fatal() => return (i, Err(e)),
+            (_, Err(_)) => return (i, Ok(list)),
+        };
+        loop {
+            // 区切り文字
+            let after_sep = match sep(rest.clone()) {
+                (after_sep, Ok(_)) => after_sep,
+                // 末尾の区切り文字が必須なのに、見つからなかった
+                (_, Err(e)) if trailing == Trailing::Require || e.is_fatal() => return (i, Err(e)),
+                (_, Err(_)) => break,
+            };
+            // 区切り文字の後ろの要素
+            match item(after_sep.clone()) {
+                (next, Ok(t)) => {
+                    // 入力が消費されていなければ、無限ループになるので中断する
+                    if next.input_len() == rest.input_len() {
+                        let err = Error::new(ErrorKind::NoProgress, Span::at(&rest));
+                        return (i, Err(err));
+                    }
+                    list.push(t);
+                    rest = next;
+                }
+                (_, Err(e)) if e.is_fatal() => return (i, Err(e)),
+                // 末尾の区切り文字が禁止されているので、区切り文字を指してエラーにする
+                (_, Err(e)) if trailing == Trailing::Forbid => {
+                    let err =
+                        Error::new(ErrorKind::TrailingSeparator, Span::new(&rest, &after_sep));
+                    let err = e
+                        .expected()
+                        .iter()
+                        .fold(err, |err, ex| err.with_expected(ex));
+                    return (i, Err(err));
+                }
+                // 末尾の区切り文字を消費して終了する
+                (_, Err(_)) => {
+                    rest = after_sep;
+                    break;
+                }
+            }
+        }
+        (rest, Ok(list))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 区切り文字で区切られた数字
+    #[test]
+    fn sep_by_success_test() {
+        let input = "1,2,3";
+        let parser = str_parser::num.sep_by(str_parser::char(','), Trailing::Forbid);
+        let (rest, result) = parser(input);
+        assert_eq!(result, Ok(vec![1, 2, 3]));
+        assert_eq!(rest, "");
+    }
+
+    // 要素がなくてもsep_byは成功する
+    #[test]
+    fn sep_by_empty_test() {
+        let input = ",1";
+        let parser = str_parser::num.sep_by(str_parser::char(','), Trailing::Allow);
+        let (rest, result) = parser(input);
+        assert_eq!(result, Ok(vec![]));
+        assert_eq!(rest, ",1");
+    }
+
+    // 末尾の区切り文字が許可されている
+    #[test]
+    fn trailing_allow_test() {
+        let input = "1,2,;";
+        let parser = str_parser::num.sep_by(str_parser::char(','), Trailing::Allow);
+        let (rest, result) = parser(input);
+        assert_eq!(result, Ok(vec![1, 2]));
+        // 末尾の区切り文字も消費される
+        assert_eq!(rest, ";");
+    }
+
+    // 末尾の区切り文字が禁止されている
+    #[test]
+    fn trailing_forbid_test() {
+        let input = "1, 2,";
+        let sep = str_parser::char(',').cat_a_uncheck(str_parser::trimer);
+        let parser = str_parser::num.sep_by1(sep, Trailing::Forbid);
+        let (rest, result) = parser(input);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::TrailingSeparator);
+        // 余った','を指している
+        assert_eq!(err.span().range(&input), 4..5);
+        assert_eq!(err.expected(), ["number"]);
+        assert_eq!(rest, "1, 2,");
+    }
+
+    // 末尾の区切り文字が必須
+    #[test]
+    fn terminated_list_missing_test() {
+        let input = "1;2;3";
+        let parser = str_parser::num.terminated_list(str_parser::char(';'));
+        let (rest, result) = parser(input);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::ParseCharError);
+        assert_eq!(err.span().range(&input), 5..5);
+        assert_eq!(rest, "1;2;3");
+    }
+}
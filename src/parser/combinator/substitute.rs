@@ -11,21 +11,22 @@ pub enum SubResult<A, B> {
 
 /// パース失敗時に別の[Parser][crate::parser::Parser]で解析する
 ///
-/// 戻り値に[Result]型を用いる[Parser][crate::parser::Parser]で使うことができます。
-pub trait Substitute<I, A, AE> {
+/// 戻り値に`Result<T, Error>`型を用いる[Parser][crate::parser::Parser]で使うことができます。
+pub trait Substitute<I, A> {
     /// メソッド呼び出し元(A)パーサーが失敗した時、メソッド引数(B)パーサーを試みるパーサーを作成します。
     ///
     /// ## 結果とエラー
     /// - メソッド呼び出し元(A)パーサーが[Ok]だった場合は、[SubResult::A]が返されます
-    /// - メソッド呼び出し元(A)パーサーが[Err]だった場合は、メソッド引数(B)パーサーを使います
+    /// - メソッド呼び出し元(A)パーサーが[Err]だった場合は、解析前の入力からメソッド引数(B)パーサーを使います
     /// - メソッド引数(B)パーサーが[Ok]だった場合は、[SubResult::B]が返されます
-    /// - メソッド引数(B)パーサーが[Err]だった場合は、`(A, B)`のタプル形式でエラーが返されます
+    /// - メソッド引数(B)パーサーが[Err]だった場合は、両方のエラーを[Error::merge]でまとめたエラーが返されます
+    /// - メソッド呼び出し元(A)パーサーが[致命的なエラー][Error::is_fatal]を返した場合は、Bを試さずにそのエラーが返されます
     ///
     /// | |A|B|
     /// |---|---|---|
     /// |解析順| 1 | 2 |
     /// |Ok| [SubResult::A] | [SubResult::B] |
-    /// |Err| Bで解析する | `Err(AE, BE)` |
+    /// |Err| Bで解析する | `Err(AE.merge(BE))` |
     ///
     /// ## Example
     /// ```
@@ -37,10 +38,22 @@ pub trait Substitute<I, A, AE> {
     ///
     /// assert_eq!(result, Ok(SubResult::A('*')))
     /// ```
-    fn sub<B, BE>(
+    ///
+    /// 候補が全て失敗した場合は、期待されていた入力がまとめられます。
+    /// ```
+    /// use my_parser_project::parser::{str_parser::string, combinator::*};
+    ///
+    /// let parser = string("say".to_string())
+    ///     .sub(string("exit".to_string()))
+    ///     .sub(string("ping".to_string()));
+    /// let (_, result) = parser("help");
+    ///
+    /// assert_eq!(result.unwrap_err().to_string(), "expected 'say', 'exit' or 'ping'");
+    /// ```
+    fn sub<B>(
         self,
-        p: impl Parser<I, Result<B, BE>>,
-    ) -> impl Parser<I, Result<SubResult<A, B>, (AE, BE)>>;
+        p: impl Parser<I, Result<B, Error>>,
+    ) -> impl Parser<I, Result<SubResult<A, B>, Error>>;
 
     /// メソッド引数(B)パーサーのエラーを無視します。  
     /// `sub`とは、戻り値の仕様が異なり、このパーサーはエラーを返すことはありません。  
@@ -50,6 +63,8 @@ pub trait Substitute<I, A, AE> {
     /// - メソッド呼び出し元(A)パーサーが[Err]だった場合は、メソッド引数(B)パーサーを使います
     /// - メソッド引数(B)パーサーが[Ok]だった場合は、[SubResult::B]が返されます。
     /// - メソッド引数(B)パーサーが[Err]だった場合も、[SubResult::B]が返されます。
+    /// - メソッド呼び出し元(A)パーサーが[致命的なエラー][Error::is_fatal]を返した場合も、Bで解析します。
+    ///   [cut][super::cut]は無視されるため、`sub`や[alt][super::alt]のように解析を打ち切ることはありません。
     ///
     /// | |A|B|
     /// |---|---|---|
@@ -64,27 +79,31 @@ pub trait Substitute<I, A, AE> {
 }
 
 // 実装
-impl<I, A, AE, P> Substitute<I, A, AE> for P
+impl<I, A, P> Substitute<I, A> for P
 where
-    P: Parser<I, Result<A, AE>>,
+    I: Clone,
+    P: Parser<I, Result<A, Error>>,
 {
-    fn sub<B, BE>(
+    fn sub<B>(
         self,
-        p: impl Parser<I, Result<B, BE>>,
-    ) -> impl Parser<I, Result<SubResult<A, B>, (AE, BE)>> {
-        move |i| match self(i) {
-            (i, Ok(a)) => (i, Ok(SubResult::A(a))),
-            (i, Err(ae)) => match p(i) {
-                (i, Ok(b)) => (i, Ok(SubResult::B(b))),
-                (i, Err(be)) => (i, Err((ae, be))),
+        p: impl Parser<I, Result<B, Error>>,
+    ) -> impl Parser<I, Result<SubResult<A, B>, Error>> {
+        move |i: I| match self(i.clone()) {
+            (rest, Ok(a)) => (rest, Ok(SubResult::A(a))),
+            // 致命的なエラーの場合は、Bを試さない
+            (_, Err(ae)) if ae.is_fatal() => (i, Err(ae)),
+            (_, Err(ae)) => match p(i.clone()) {
+                (rest, Ok(b)) => (rest, Ok(SubResult::B(b))),
+                // 両方のエラーをまとめ、入力は巻き戻す
+                (_, Err(be)) => (i, Err(ae.merge(be))),
             },
         }
     }
 
     fn sub_uncheck<B>(self, p: impl Parser<I, B>) -> impl Parser<I, SubResult<A, B>> {
-        move |i| match self(i) {
-            (i, Ok(a)) => (i, SubResult::A(a)),
-            (i, Err(_)) => {
+        move |i: I| match self(i.clone()) {
+            (rest, Ok(a)) => (rest, SubResult::A(a)),
+            (_, Err(_)) => {
                 let (i, b) = p(i);
                 (i, SubResult::B(b))
             }
@@ -121,7 +140,49 @@ mod tests {
         let input = "a123";
         let parser = str_parser::char('*').sub(str_parser::char('+'));
         let (_, result) = parser(input);
-        assert!(matches!(result, Err(_)))
+        assert!(result.is_err())
+    }
+    // 三つの候補のエラーが平らにまとめられる
+    #[test]
+    fn sub_failure_expected() {
+        let input = "a123";
+        let parser = str_parser::char('*')
+            .sub(str_parser::char('+'))
+            .sub(str_parser::num);
+        let (rest, result) = parser(input);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Expected);
+        assert_eq!(err.expected(), ["'*'", "'+'", "number"]);
+        assert_eq!(err.to_string(), "expected '*', '+' or number");
+        // 入力は消費されない
+        assert_eq!(rest, "a123");
+    }
+    // より先まで進んだ候補のエラーが優先される
+    #[test]
+    fn sub_failure_furthest() {
+        let input = "*a";
+        let parser = str_parser::char('*')
+            .cat(str_parser::num)
+            .sub(str_parser::char('+'));
+        let (rest, result) = parser(input);
+        let err = result.unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::ParseNumError(_)));
+        assert_eq!(err.span().range(&input), 1..2);
+        assert_eq!(rest, "*a");
+    }
+
+    // Aが致命的なエラーの時は、Bを試さない
+    #[test]
+    fn sub_failure_fatal() {
+        let input = "*a";
+        let parser = str_parser::char('*')
+            .cat(cut(str_parser::num))
+            .sub(str_parser::char('*').cat_b(str_parser::take_while1(|c| c.is_alphabetic())));
+        let (rest, result) = parser(input);
+        let err = result.unwrap_err();
+        assert!(err.is_fatal());
+        assert!(matches!(err.kind(), ErrorKind::ParseNumError(_)));
+        assert_eq!(rest, "*a");
     }
 
     // Aのパーサーで成功する時
@@ -0,0 +1,77 @@
+use super::*;
+
+/// パーサーが解析した範囲を取得するメソッドを提供する
+///
+/// 戻り値に[Result]型を用いる[Parser][crate::parser::Parser]で使うことができます。
+pub trait WithSpan<I, T, E> {
+    /// 解析に成功した場合、結果と一緒に解析した範囲を返すパーサーを作成します。
+    ///
+    /// ## 結果とエラー
+    /// - メソッド呼び出し元のパーサーが[Ok]だった場合は、`(結果, Span)`のタプルが返されます
+    /// - メソッド呼び出し元のパーサーが[Err]だった場合は、そのままエラーが返されます
+    ///
+    /// [Span]は末尾からの距離で保持されているため、元の入力を与えて位置を求めます。
+    ///
+    /// ## Example
+    /// ```
+    /// use my_parser_project::parser::{str_parser::{char, num}, combinator::*};
+    ///
+    /// let src = "*123;";
+    /// let parser = char('*').cat_b(num.with_span());
+    /// let (_, result) = parser(src);
+    ///
+    /// let (n, span) = result.unwrap();
+    /// assert_eq!(n, 123);
+    /// assert_eq!(span.range(&src), 1..4);
+    /// ```
+    fn with_span(self) -> impl Parser<I, Result<(T, Span), E>>;
+}
+
+// 実装
+impl<I, T, E, P> WithSpan<I, T, E> for P
+where
+    I: Input,
+    P: Parser<I, Result<T, E>>,
+{
+    fn with_span(self) -> impl Parser<I, Result<(T, Span), E>> {
+        move |i: I| match self(i.clone()) {
+            // 解析前と解析後の入力の差が、解析した範囲になる
+            (rest, Ok(t)) => {
+                let span = Span::new(&i, &rest);
+                (rest, Ok((t, span)))
+            }
+            (rest, Err(e)) => (rest, Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 成功した場合は範囲が返される
+    #[test]
+    fn with_span_success_test() {
+        let src = "ab\ncd";
+        let parser = str_parser::string("cd".to_string()).with_span();
+        let (_, result) = parser(&src[3..]);
+        let (s, span) = result.unwrap();
+        assert_eq!(s, "cd");
+        assert_eq!(span.range(&src), 3..5);
+        assert_eq!(span.start(src).line, 2);
+        assert_eq!(span.end(src).column, 3);
+    }
+
+    // 失敗した場合はそのままエラーが返される
+    #[test]
+    fn with_span_failure_test() {
+        let src = "abc";
+        let parser = str_parser::num.with_span();
+        let (rest, result) = parser(src);
+        assert_eq!(rest, "abc");
+        assert!(matches!(
+            result.unwrap_err().kind(),
+            &ErrorKind::ParseNumError(_)
+        ));
+    }
+}
@@ -4,21 +4,192 @@ use super::*;
 ///
 /// [crate::parser]以下に含まれるパーサー関数のエラーを管理します。  
 /// [super::ErrorKind]にジャンル分けされたエラー原因が含まれます。  
+/// また、解析に失敗した場所を[Span]として保持しています。
 ///
+/// # 位置について
+/// パーサーは残りの入力しか知らないため、位置を求めるには元の入力が必要です。  
+/// トップレベルのパーサーに渡した入力を[position][Error::position]に与えてください。
+///
+/// ```rust
+/// use my_parser_project::parser::str_parser::char;
+///
+/// let src = "*123";
+/// let (_, result) = char('*')(&src[1..]);
+/// let pos = result.unwrap_err().position(src);
+/// assert_eq!((pos.offset, pos.line, pos.column), (1, 1, 2));
+/// ```
+///
+/// # 期待されていた入力について
+/// 各パーサーは、解析に失敗した時に「何が期待されていたか」を[expected][Error::expected]に記録します。  
+/// [sub][crate::parser::combinator::Substitute::sub]などで複数の候補を試した場合は、
+/// [merge][Error::merge]によって候補がひとつのエラーにまとめられます。
+///
+/// # 文脈について
+/// [context][Error::context]で、何を解析している途中で失敗したかを表すラベルを付けられます。  
+/// ラベルを付けたエラーは、元のエラーを[source][std::error::Error::source]として持ちます。
+///
+/// # 致命的なエラーについて
+/// [cut][crate::parser::combinator::cut]を通ったエラーは、致命的なエラーになります([is_fatal][Error::is_fatal])。  
+/// 致命的なエラーが返された場合、[sub][crate::parser::combinator::Substitute::sub]などは他の候補を試さずに、そのままエラーを返します。
 #[derive(Debug, PartialEq)]
 pub struct Error {
     kind: ErrorKind,
+    span: Span,
+    expected: Vec<String>,
+    // 文脈ラベルを付ける前のエラー
+    inner: Option<Box<Error>>,
+    // 他の候補を試さずに、解析を打ち切るかどうか
+    fatal: bool,
 }
 
 impl Error {
     /// エラーを作成
-    pub fn new(kind: ErrorKind) -> Self {
-        Self { kind }
+    ///
+    /// spanには、解析に失敗した範囲を指定します。
+    pub fn new(kind: ErrorKind, span: Span) -> Self {
+        Self {
+            kind,
+            span,
+            expected: Vec::new(),
+            inner: None,
+            fatal: false,
+        }
+    }
+    /// 期待されていた入力を追加する
+    ///
+    /// `'x'` や `number` など、ユーザーに表示できる形式で指定します。
+    pub fn with_expected(mut self, expected: impl Into<String>) -> Self {
+        let expected = expected.into();
+        if !self.expected.contains(&expected) {
+            self.expected.push(expected);
+        }
+        self
+    }
+    /// 期待されていた入力を、まとめて追加する
+    ///
+    /// [with_expected][Error::with_expected]と異なり、重複の確認をしません。  
+    /// 重複を除いた一覧を、あらかじめ用意しておける場合に使います。
+    pub(crate) fn with_expected_list(mut self, expected: &[String]) -> Self {
+        self.expected.extend_from_slice(expected);
+        self
     }
     /// ErrorKindを取得する
     pub fn kind(&self) -> &ErrorKind {
         &self.kind
     }
+    /// 解析に失敗した範囲を取得する
+    pub fn span(&self) -> Span {
+        self.span
+    }
+    /// 解析に失敗した位置を、元の入力から求める
+    pub fn position(&self, src: &str) -> Position {
+        self.span.start(src)
+    }
+    /// 期待されていた入力の一覧を取得する
+    pub fn expected(&self) -> &[String] {
+        &self.expected
+    }
+    /// 致命的なエラーにする
+    ///
+    /// 通常は[cut][crate::parser::combinator::cut]コンビネーターを使います。
+    pub fn cut(mut self) -> Self {
+        self.fatal = true;
+        self
+    }
+    /// 致命的なエラーかどうか
+    ///
+    /// 致命的なエラーは、代替のパーサーを試したり、省略したりせずに、そのまま呼び出し元に返されます。
+    pub fn is_fatal(&self) -> bool {
+        self.fatal
+    }
+    /// 文脈ラベルを付ける
+    ///
+    /// 元のエラーを包み、[kind][Error::kind]が [Context][ErrorKind::Context] のエラーを作成します。  
+    /// 範囲と期待されていた入力は、元のエラーのものが引き継がれます。  
+    /// 通常は[context][crate::parser::combinator::context]コンビネーターを使います。
+    ///
+    /// # Example
+    /// ```rust
+    /// use my_parser_project::parser::{str_parser::char, ErrorKind};
+    ///
+    /// let (_, result) = char(':')("GET");
+    /// let err = result.unwrap_err().context("method").context("request line");
+    /// assert_eq!(err.to_string(), "in request line → in method → failed parse to char");
+    /// assert_eq!(err.contexts().collect::<Vec<_>>(), ["request line", "method"]);
+    /// assert_eq!(err.root().kind(), &ErrorKind::ParseCharError);
+    /// ```
+    pub fn context(self, label: impl Into<String>) -> Error {
+        Error {
+            kind: ErrorKind::Context(label.into()),
+            span: self.span,
+            expected: self.expected.clone(),
+            fatal: self.fatal,
+            inner: Some(Box::new(self)),
+        }
+    }
+    /// 文脈ラベルを、外側から順に取得する
+    pub fn contexts(&self) -> impl Iterator<Item = &str> {
+        std::iter::successors(Some(self), |e| e.inner.as_deref()).filter_map(|e| match &e.kind {
+            ErrorKind::Context(label) => Some(label.as_str()),
+            _ => None,
+        })
+    }
+    /// 文脈ラベルを取り除いた、元のエラーを取得する
+    pub fn root(&self) -> &Error {
+        match &self.inner {
+            Some(inner) => inner.root(),
+            None => self,
+        }
+    }
+    /// 二つのエラーをまとめる
+    ///
+    /// 代替となるパーサーが両方とも失敗した時に使います。
+    ///
+    /// - より先まで解析が進んだ方のエラーが優先されます。
+    /// - 同じ位置で失敗した場合は、[kind][Error::kind]が [Expected][ErrorKind::Expected] になり、
+    ///   期待されていた入力が重複なく並べられます。
+    /// - [致命的なエラー][Error::is_fatal]は、位置に関わらず優先されます。
+    ///
+    /// # Example
+    /// ```rust
+    /// use my_parser_project::parser::{str_parser::char, ErrorKind};
+    ///
+    /// let (_, a) = char('+')("*");
+    /// let (_, b) = char('-')("*");
+    /// let err = a.unwrap_err().merge(b.unwrap_err());
+    /// assert_eq!(err.kind(), &ErrorKind::Expected);
+    /// assert_eq!(err.expected(), ["'+'", "'-'"]);
+    /// assert_eq!(err.to_string(), "expected '+' or '-'");
+    /// ```
+    pub fn merge(self, other: Error) -> Error {
+        if self.fatal {
+            return self;
+        }
+        if other.fatal {
+            return other;
+        }
+        match self.span.cmp_start(&other.span) {
+            std::cmp::Ordering::Greater => self,
+            std::cmp::Ordering::Less => other,
+            std::cmp::Ordering::Equal => {
+                // 長い方の範囲を採用する
+                let span = match self.span.len() < other.span.len() {
+                    true => other.span,
+                    false => self.span,
+                };
+                other.expected.into_iter().fold(
+                    Error {
+                        kind: ErrorKind::Expected,
+                        span,
+                        expected: self.expected,
+                        inner: None,
+                        fatal: false,
+                    },
+                    |e, expected| e.with_expected(expected),
+                )
+            }
+        }
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -27,16 +198,69 @@ impl std::fmt::Display for Error {
             ErrorKind::ParseNumError(parse_int_error) => {
                 write!(f, "failed parse to number\n{}", parse_int_error)
             }
+            ErrorKind::ParseIntegerError => {
+                write!(f, "failed parse to integer, ")?;
+                self.fmt_expected(f)
+            }
+            ErrorKind::IntegerOverflow => write!(f, "integer is out of range"),
+            ErrorKind::ParseFloatError => write!(f, "failed parse to float"),
             ErrorKind::ParseCharError => write!(f, "failed parse to char"),
             ErrorKind::ParseStringError => write!(f, "failed parse to string"),
+            ErrorKind::ParseTakeWhileError => write!(f, "not enough matching characters"),
+            ErrorKind::ParseKeywordError => {
+                write!(f, "unknown keyword, ")?;
+                self.fmt_expected(f)
+            }
+            ErrorKind::Unexpected => write!(f, "unexpected input"),
+            ErrorKind::ParseTokenError => write!(f, "failed parse to token"),
+            ErrorKind::UnknownToken => write!(f, "unrecognised input"),
+            ErrorKind::ParseByteError => write!(f, "failed parse to byte"),
+            ErrorKind::ParseTagError => write!(f, "failed parse to tag"),
+            ErrorKind::ParseTakeUntilError => {
+                write!(f, "failed parse to take until, ")?;
+                self.fmt_expected(f)
+            }
+            ErrorKind::Incomplete(needed) => write!(f, "needed {} more bytes", needed),
+            ErrorKind::NoProgress => write!(f, "parser did not consume any input in a loop"),
+            ErrorKind::TrailingSeparator => write!(f, "trailing separator is not allowed"),
+            ErrorKind::Expected if self.expected.is_empty() => {
+                write!(f, "no alternative matched")
+            }
+            ErrorKind::Expected => self.fmt_expected(f),
+            ErrorKind::VerifyError => write!(f, "verification failed"),
+            ErrorKind::Custom(e) => write!(f, "{}", e),
+            ErrorKind::Context(label) => match &self.inner {
+                Some(inner) => write!(f, "in {} → {}", label, inner),
+                None => write!(f, "in {}", label),
+            },
         }
     }
 }
 
+impl Error {
+    // "expected 'a', 'b' or 'c'" の形式で、期待されていた入力を表示する
+    pub(super) fn fmt_expected(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected ")?;
+        let len = self.expected.len();
+        for (n, expected) in self.expected.iter().enumerate() {
+            match n {
+                0 => write!(f, "{}", expected)?,
+                n if n + 1 == len => write!(f, " or {}", expected)?,
+                _ => write!(f, ", {}", expected)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        if let Some(inner) = &self.inner {
+            return Some(inner.as_ref());
+        }
         match &self.kind {
             ErrorKind::ParseNumError(parse_int_error) => Some(parse_int_error),
+            ErrorKind::Custom(e) => Some(e.as_ref()),
             _ => None,
         }
     }
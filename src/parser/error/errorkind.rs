@@ -2,13 +2,97 @@
 ///
 /// - [Error::kind][super::Error::kind] で使用されます。
 /// - 各項目の詳しい解説は、エラーを返す関数の項目を参照してください。
-#[derive(PartialEq, Debug)]
+/// - 利用者が独自のエラーを返したい場合は、[Custom][ErrorKind::Custom]を使います。
+#[derive(Debug)]
 // 各Kindの解説は、実際にエラーを返す関数の項で説明
 pub enum ErrorKind {
     /// [num][crate::parser::str_parser::num] パーサーのエラー
     ParseNumError(std::num::ParseIntError),
+    /// [int][crate::parser::str_parser::int] パーサーで、数字が見つからなかったエラー
+    ParseIntegerError,
+    /// [int][crate::parser::str_parser::int] パーサーで、値が型に収まらなかったエラー
+    IntegerOverflow,
+    /// [float][crate::parser::str_parser::float] パーサーのエラー
+    ParseFloatError,
     /// [char][crate::parser::str_parser::char] パーサーのエラー
     ParseCharError,
     /// [string][crate::parser::str_parser::string] パーサーのエラー
     ParseStringError,
+    /// [take_while1][crate::parser::str_parser::take_while1] などのパーサーで、条件を満たす文字が足りないエラー
+    ParseTakeWhileError,
+    /// [keyword_table][crate::parser::str_parser::keyword_table] パーサーのエラー
+    ParseKeywordError,
+    /// [not][crate::parser::combinator::not] などで、一致してはいけない入力が見つかったエラー
+    Unexpected,
+    /// [token][crate::parser::token_parser::token] などのパーサーのエラー
+    ParseTokenError,
+    /// [Lexer][crate::parser::lexer::Lexer] で、どのルールにも一致しなかったエラー
+    UnknownToken,
+    /// [byte][crate::parser::bytes_parser::byte] パーサーのエラー
+    ParseByteError,
+    /// [tag][crate::parser::bytes_parser::tag] パーサーのエラー
+    ParseTagError,
+    /// [take_until][crate::parser::bytes_parser::take_until] パーサーで、条件のバイト列が見つからなかったエラー
+    ParseTakeUntilError,
+    /// 入力が足りないエラー
+    ///
+    /// [take][crate::parser::bytes_parser::take] などで返されます。不足しているバイト数が入ります。
+    Incomplete(usize),
+    /// 複数の候補がどれも見つからなかったエラー
+    ///
+    /// [Error::merge][super::Error::merge] でまとめられたエラーです。  
+    /// 期待されていた候補は [Error::expected][super::Error::expected] で取得できます。
+    Expected,
+    /// パーサーが入力を消費せずに成功し続けるエラー
+    ///
+    /// [Repeat][crate::parser::combinator::Repeat] などの繰り返しで、無限ループを防ぐために返されます。
+    NoProgress,
+    /// リストの末尾に、禁止されている区切り文字が残っているエラー
+    ///
+    /// [Separated][crate::parser::combinator::Separated] で返されます。  
+    /// [span][super::Error::span]は、余った区切り文字を指します。
+    TrailingSeparator,
+    /// 文脈ラベルが付けられたエラー
+    ///
+    /// [context][crate::parser::combinator::context] で返されます。ラベルが入ります。  
+    /// 元のエラーは [Error::root][super::Error::root] や [source][std::error::Error::source] で取得できます。
+    Context(String),
+    /// [verify][crate::parser::combinator::MapRes::verify] で、条件を満たさなかったエラー
+    VerifyError,
+    /// 利用者が定義したエラー
+    ///
+    /// [map_res][crate::parser::combinator::MapRes::map_res] などで、関数が返したエラーが入ります。  
+    /// 元のエラーは [source][std::error::Error::source] で取得できます。
+    Custom(Box<dyn std::error::Error + Send + Sync>),
+}
+
+// Customに入るエラーは比較できないため、表示した文字列で比較する
+impl PartialEq for ErrorKind {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ErrorKind::ParseNumError(a), ErrorKind::ParseNumError(b)) => a == b,
+            (ErrorKind::Incomplete(a), ErrorKind::Incomplete(b)) => a == b,
+            (ErrorKind::Context(a), ErrorKind::Context(b)) => a == b,
+            (ErrorKind::Custom(a), ErrorKind::Custom(b)) => a.to_string() == b.to_string(),
+            // 値を持たない項目は、種類だけを比較する
+            (ErrorKind::ParseIntegerError, ErrorKind::ParseIntegerError)
+            | (ErrorKind::IntegerOverflow, ErrorKind::IntegerOverflow)
+            | (ErrorKind::ParseFloatError, ErrorKind::ParseFloatError)
+            | (ErrorKind::ParseCharError, ErrorKind::ParseCharError)
+            | (ErrorKind::ParseStringError, ErrorKind::ParseStringError)
+            | (ErrorKind::ParseTakeWhileError, ErrorKind::ParseTakeWhileError)
+            | (ErrorKind::ParseKeywordError, ErrorKind::ParseKeywordError)
+            | (ErrorKind::Unexpected, ErrorKind::Unexpected)
+            | (ErrorKind::ParseTokenError, ErrorKind::ParseTokenError)
+            | (ErrorKind::UnknownToken, ErrorKind::UnknownToken)
+            | (ErrorKind::ParseByteError, ErrorKind::ParseByteError)
+            | (ErrorKind::ParseTagError, ErrorKind::ParseTagError)
+            | (ErrorKind::ParseTakeUntilError, ErrorKind::ParseTakeUntilError)
+            | (ErrorKind::Expected, ErrorKind::Expected)
+            | (ErrorKind::NoProgress, ErrorKind::NoProgress)
+            | (ErrorKind::TrailingSeparator, ErrorKind::TrailingSeparator)
+            | (ErrorKind::VerifyError, ErrorKind::VerifyError) => true,
+            _ => false,
+        }
+    }
 }
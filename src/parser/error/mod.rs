@@ -1,5 +1,12 @@
+#[allow(clippy::module_inception)]
 mod error;
 pub use error::*;
 
 mod errorkind;
 pub use errorkind::*;
+
+mod span;
+pub use span::*;
+
+mod report;
+pub use report::*;
@@ -0,0 +1,257 @@
+use super::*;
+
+/// エラーを、入力の該当箇所と一緒に表示する構造体
+///
+/// [Error::report]で作成し、[Display][std::fmt::Display]で表示します。  
+/// 失敗した行を表示し、失敗した範囲に`^`で下線を引きます。
+/// 期待されていた入力と、実際に見つかった入力も表示されます。
+///
+/// ```text
+/// error: failed parse to char
+///  --> 2:7
+///   |
+/// 2 | b = 2 ?
+///   |       ^ expected ';', found '?'
+/// ```
+///
+/// # 表示について
+/// - 全角文字は2文字分の幅として、下線の位置を揃えます。
+/// - タブは4文字分の空白に置き換えて表示します。
+/// - [color][Report::color]で、ANSIエスケープシーケンスによる色付けを有効にできます。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::str_parser::char;
+///
+/// let src = "a = 1;\nb = 2 ?";
+/// let (_, result) = char(';')(&src[13..]);
+/// let err = result.unwrap_err();
+///
+/// let report = err.report(src).to_string();
+/// assert_eq!(
+///     report,
+///     "error: failed parse to char\n \
+///      --> 2:7\n  \
+///       |\n\
+///      2 | b = 2 ?\n  \
+///       |       ^ expected ';', found '?'\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Report<'a> {
+    error: &'a Error,
+    src: &'a str,
+    color: bool,
+}
+
+impl Error {
+    /// エラーを表示するための[Report]を作成する
+    ///
+    /// srcには、トップレベルのパーサーに渡した元の入力を指定します。
+    pub fn report<'a>(&'a self, src: &'a str) -> Report<'a> {
+        Report {
+            error: self,
+            src,
+            color: false,
+        }
+    }
+}
+
+impl Report<'_> {
+    /// 色付けを切り替える
+    ///
+    /// 有効にすると、ANSIエスケープシーケンスで色を付けて表示します。初期値は無効です。
+    pub fn color(mut self, enabled: bool) -> Self {
+        self.color = enabled;
+        self
+    }
+}
+
+// 色付けに使うエスケープシーケンス
+const RED: &str = "\x1b[31m";
+const BLUE: &str = "\x1b[34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+impl std::fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (red, blue, bold, reset) = match self.color {
+            true => (RED, BLUE, BOLD, RESET),
+            false => ("", "", "", ""),
+        };
+        let src = self.src;
+        let range = self.error.span().range(&src);
+        let pos = self.error.position(src);
+        // 解析した入力と異なるsrcが渡されると、文字の途中を指すことがあるので、文字の先頭まで戻す
+        let range = floor_char_boundary(src, range.start)..floor_char_boundary(src, range.end);
+
+        // 失敗した位置を含む行
+        let line_start = src[..range.start].rfind('\n').map_or(0, |p| p + 1);
+        let line_end = src[range.start..]
+            .find('\n')
+            .map_or(src.len(), |p| range.start + p);
+        let line = src[line_start..line_end].trim_end_matches('\r');
+        // 下線は行の終わりまで
+        // CRLFの改行で失敗した場合は、取り除いた\rより後ろから始まることがある
+        let underline_end = (line_start + line.len()).max(range.start);
+        let target = &src[range.start..range.end.clamp(range.start, underline_end)];
+        let pad = width(&src[line_start..range.start]);
+        let len = width(target).max(1);
+        let gutter = pos.line.to_string().len();
+
+        writeln!(f, "{bold}{red}error{reset}{bold}: {}{reset}", self.error)?;
+        writeln!(f, "{:gutter$}{blue}-->{reset} {}", "", pos)?;
+        writeln!(f, "{:gutter$} {blue}|{reset}", "")?;
+        writeln!(
+            f,
+            "{blue}{}{reset} {blue}|{reset} {}",
+            pos.line,
+            line.replace('\t', "    ")
+        )?;
+        write!(
+            f,
+            "{:gutter$} {blue}|{reset} {:pad$}{red}{}",
+            "",
+            "",
+            "^".repeat(len)
+        )?;
+        if !self.error.expected().is_empty() {
+            write!(f, " ")?;
+            self.error.fmt_expected(f)?;
+            write!(f, ",")?;
+        }
+        writeln!(f, " found {}{reset}", found(&src[range.start..], target))
+    }
+}
+
+// 見つかった入力を表示用の文字列にする
+// 範囲が空の場合は、次の1文字を表示する
+fn found(rest: &str, target: &str) -> String {
+    let text = match target.is_empty() {
+        true => rest.chars().next().map(String::from),
+        false => Some(target.to_string()),
+    };
+    match text {
+        Some(text) => format!("'{}'", text.escape_debug()),
+        None => "end of input".to_string(),
+    }
+}
+
+// offset以前で、最も近い文字の境界
+fn floor_char_boundary(src: &str, offset: usize) -> usize {
+    (0..=offset.min(src.len()))
+        .rev()
+        .find(|p| src.is_char_boundary(*p))
+        .unwrap_or(0)
+}
+
+// 端末上で表示した時の幅
+fn width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+// 端末上で表示した時の文字の幅
+// 全角文字は2、タブは4として数える
+fn char_width(c: char) -> usize {
+    match c {
+        '\t' => 4,
+        '\u{1100}'..='\u{115F}'
+        | '\u{2E80}'..='\u{303E}'
+        | '\u{3041}'..='\u{33FF}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{A000}'..='\u{A4CF}'
+        | '\u{AC00}'..='\u{D7A3}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{FE30}'..='\u{FE4F}'
+        | '\u{FF00}'..='\u{FF60}'
+        | '\u{FFE0}'..='\u{FFE6}'
+        | '\u{1F300}'..='\u{1F64F}'
+        | '\u{1F900}'..='\u{1F9FF}'
+        | '\u{20000}'..='\u{3FFFD}' => 2,
+        c if c.is_control() => 0,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::str_parser::{char, string};
+
+    // 全角文字の幅に合わせて下線を引く
+    #[test]
+    fn wide_char_test() {
+        let src = "名前＝値";
+        let (_, result) = char('=')(&src[6..]);
+        let report = result.unwrap_err().report(src).to_string();
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines[3], "1 | 名前＝値");
+        assert_eq!(lines[4], "  |     ^^ expected '=', found '＝'");
+    }
+
+    // 入力の終わりで失敗した
+    #[test]
+    fn end_of_input_test() {
+        let src = "say";
+        let (_, result) = string("say hello".to_string())(src);
+        let report = result.unwrap_err().report(src).to_string();
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines[1], " --> 1:1");
+        assert_eq!(lines[4], "  | ^^^ expected 'say hello', found 'say'");
+
+        let (_, result) = char(';')("");
+        let report = result.unwrap_err().report("").to_string();
+        assert!(report.ends_with("^ expected ';', found end of input\n"));
+    }
+
+    // CRLFの改行の位置で失敗しても、行の終わりを指す
+    #[test]
+    fn crlf_test() {
+        let src = "a\r\nb";
+        let (_, result) = char(';')(&src[2..]);
+        let report = result.unwrap_err().report(src).to_string();
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines[3], "1 | a");
+        assert_eq!(lines[4], "  |  ^ expected ';', found '\\n'");
+
+        let src = "a\r\r\nb";
+        for offset in [2, 3] {
+            let (_, result) = char(';')(&src[offset..]);
+            let report = result.unwrap_err().report(src).to_string();
+            assert_eq!(report.lines().nth(3), Some("1 | a"));
+        }
+    }
+
+    // 解析した入力と異なるsrcを渡しても、文字の途中で切らない
+    #[test]
+    fn mismatched_src_test() {
+        let (_, result) = char(';')(&"abcd"[3..]);
+        let report = result.unwrap_err().report("aあ").to_string();
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines[3], "1 | aあ");
+        assert_eq!(lines[4], "  |  ^^ expected ';', found 'あ'");
+    }
+
+    // 行番号の幅に合わせる
+    #[test]
+    fn gutter_test() {
+        let src = "\n".repeat(9) + "\tx";
+        let (_, result) = char('y')(&src[10..]);
+        let report = result.unwrap_err().report(&src).to_string();
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines[1], "  --> 10:2");
+        assert_eq!(lines[3], "10 |     x");
+        assert_eq!(lines[4], "   |     ^ expected 'y', found 'x'");
+    }
+
+    // 色付け
+    #[test]
+    fn color_test() {
+        let (_, result) = char('x')("y");
+        let err = result.unwrap_err();
+        let plain = err.report("y").to_string();
+        let colored = err.report("y").color(true).to_string();
+        assert!(!plain.contains('\x1b'));
+        assert!(colored.starts_with("\x1b[1m\x1b[31merror\x1b[0m"));
+    }
+}
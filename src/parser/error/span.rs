@@ -0,0 +1,152 @@
+use std::ops::Range;
+
+use crate::parser::Input;
+
+/// 入力上の範囲を表す構造体
+///
+/// パーサーは残りの入力しか知らないため、位置は入力の**末尾からの距離**で保持しています。  
+/// トップレベルのパーサーに渡した元の入力を与えることで、先頭からの位置に変換できます。
+///
+/// - [range][Span::range] で、先頭からのオフセットの範囲を取得できます。
+/// - [start][Span::start], [end][Span::end] で、行と列を含む[Position]を取得できます。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::Span;
+///
+/// let src = "hello world";
+/// // "world"を指す範囲
+/// let span = Span::new(&&src[6..], &&src[11..]);
+/// assert_eq!(span.range(&src), 6..11);
+/// assert_eq!(&src[span.range(&src)], "world");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    // 開始位置の、入力の末尾からの距離
+    start: usize,
+    // 終了位置の、入力の末尾からの距離
+    end: usize,
+}
+
+impl Span {
+    /// 解析前の入力と、解析後の入力から範囲を作成
+    ///
+    /// `before`から`after`までの間に消費された部分が範囲になります。
+    pub fn new<I: Input>(before: &I, after: &I) -> Self {
+        let start = before.input_len();
+        let end = after.input_len().min(start);
+        Self { start, end }
+    }
+    /// 入力の先頭を指す、長さ0の範囲を作成
+    pub fn at<I: Input>(i: &I) -> Self {
+        Self::new(i, i)
+    }
+    /// 範囲の長さ
+    pub fn len(&self) -> usize {
+        self.start - self.end
+    }
+    /// 範囲の長さが0かどうか
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+    // 開始位置を比較する
+    // 入力の先まで進んでいる方が大きくなる
+    pub(crate) fn cmp_start(&self, other: &Span) -> std::cmp::Ordering {
+        other.start.cmp(&self.start)
+    }
+    /// 元の入力の先頭から数えた、範囲のオフセットを取得する
+    pub fn range<I: Input>(&self, src: &I) -> Range<usize> {
+        let len = src.input_len();
+        len.saturating_sub(self.start)..len.saturating_sub(self.end)
+    }
+    /// 範囲の開始位置を取得する
+    pub fn start(&self, src: &str) -> Position {
+        Position::new(src, self.range(&src).start)
+    }
+    /// 範囲の終了位置を取得する
+    pub fn end(&self, src: &str) -> Position {
+        Position::new(src, self.range(&src).end)
+    }
+}
+
+/// 入力上の位置を表す構造体
+///
+/// - offset: 先頭からのバイト数 (0始まり)
+/// - line: 行番号 (1始まり)
+/// - column: 行頭からの文字数 (1始まり)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    /// 元の入力とオフセットから、行と列を求める
+    ///
+    /// # Example
+    /// ```rust
+    /// use my_parser_project::parser::Position;
+    ///
+    /// let pos = Position::new("abc\ndef", 5);
+    /// assert_eq!(pos.line, 2);
+    /// assert_eq!(pos.column, 2);
+    /// ```
+    pub fn new(src: &str, offset: usize) -> Self {
+        let offset = offset.min(src.len());
+        let head = &src.as_bytes()[..offset];
+        let line_start = head.iter().rposition(|b| *b == b'\n').map_or(0, |p| p + 1);
+        let line = head.iter().filter(|b| **b == b'\n').count() + 1;
+        // 列は文字数で数える
+        let column = src
+            .get(line_start..offset)
+            .map_or(offset - line_start, |s| s.chars().count())
+            + 1;
+        Self {
+            offset,
+            line,
+            column,
+        }
+    }
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 残りの入力から範囲を求める
+    #[test]
+    fn span_range_test() {
+        let src = "*123abc";
+        let span = Span::new(&&src[1..], &&src[4..]);
+        assert_eq!(span.range(&src), 1..4);
+        assert_eq!(span.len(), 3);
+        assert_eq!(&src[span.range(&src)], "123");
+    }
+
+    // 複数行の入力で行と列を求める
+    #[test]
+    fn position_test() {
+        let src = "first\nsecond\nthird";
+        let span = Span::at(&&src[8..]);
+        let pos = span.start(src);
+        assert_eq!(pos.offset, 8);
+        assert_eq!(pos.line, 2);
+        assert_eq!(pos.column, 3);
+    }
+
+    // 列はバイト数ではなく文字数で数える
+    #[test]
+    fn position_multibyte_test() {
+        let src = "あいう*";
+        let pos = Position::new(src, "あいう".len());
+        assert_eq!(pos.line, 1);
+        assert_eq!(pos.column, 4);
+    }
+}
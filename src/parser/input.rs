@@ -0,0 +1,95 @@
+/// パーサーの入力として扱える型
+///
+/// パーサーは入力の先頭から解析を行い、残った部分を返します。  
+/// そのため、パーサーが知ることができるのは**残りの入力**だけです。  
+/// このトレイトは、入力を要素の並びとして扱うための操作と、
+/// 残りの入力の長さから元の入力のどこを解析しているかを求める操作を提供します。
+///
+/// - [`&str`][str]では、要素は[char]で、長さとオフセットはバイト数です。
+/// - [`&[T]`][slice]では、要素は`T`で、長さとオフセットは要素数です。
+///   [`&[u8]`][slice]のバイト列や、字句解析済みのトークン列もこれに含まれます。
+///
+/// [token_parser][crate::parser::token_parser]のパーサーは、このトレイトを実装した入力であれば何でも扱えます。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::Input;
+///
+/// let src = "あいう";
+/// let (head, rest) = src.split_at_offset(3);
+/// assert_eq!((head, rest), ("あ", "いう"));
+/// assert_eq!(rest.offset_from(&src), 3);
+///
+/// let elements: Vec<_> = rest.iter_elements().collect();
+/// assert_eq!(elements, [(0, 'い'), (3, 'う')]);
+/// ```
+pub trait Input: Clone {
+    /// 入力の要素の型
+    type Item: Clone;
+    /// 残りの入力の長さを返します
+    fn input_len(&self) -> usize;
+    /// 入力をオフセットの位置で二つに分けます
+    ///
+    /// `(オフセットより前, オフセット以降)`の順で返します。
+    fn split_at_offset(&self, offset: usize) -> (Self, Self);
+    /// 入力の要素を、先頭からのオフセットと共に順に返します
+    fn iter_elements(&self) -> impl Iterator<Item = (usize, Self::Item)>;
+    /// 元の入力の先頭から数えた、この入力のオフセットを返します
+    ///
+    /// `self`は`src`を解析した残りである必要があります。
+    fn offset_from(&self, src: &Self) -> usize {
+        src.input_len().saturating_sub(self.input_len())
+    }
+}
+
+impl Input for &str {
+    type Item = char;
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+    fn split_at_offset(&self, offset: usize) -> (Self, Self) {
+        self.split_at(offset)
+    }
+    fn iter_elements(&self) -> impl Iterator<Item = (usize, Self::Item)> {
+        self.char_indices()
+    }
+}
+
+impl<T: Clone> Input for &[T] {
+    type Item = T;
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+    fn split_at_offset(&self, offset: usize) -> (Self, Self) {
+        self.split_at(offset)
+    }
+    fn iter_elements(&self) -> impl Iterator<Item = (usize, Self::Item)> {
+        self.iter().cloned().enumerate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 文字列はバイト単位のオフセットで、文字ごとに分けられる
+    #[test]
+    fn str_test() {
+        let src = "aé=";
+        let offsets: Vec<_> = src.iter_elements().map(|(p, _)| p).collect();
+        assert_eq!(offsets, [0, 1, 3]);
+        let (_, rest) = src.split_at_offset(3);
+        assert_eq!(rest, "=");
+        assert_eq!(rest.offset_from(&src), 3);
+    }
+
+    // スライスは要素単位
+    #[test]
+    fn slice_test() {
+        let src: &[u16] = &[10, 20, 30];
+        let (head, rest) = src.split_at_offset(1);
+        assert_eq!(head, &[10]);
+        assert_eq!(rest.iter_elements().collect::<Vec<_>>(), [(0, 20), (1, 30)]);
+        assert_eq!(rest.offset_from(&src), 1);
+    }
+}
@@ -0,0 +1,255 @@
+use super::*;
+
+// ルールとして登録されたパーサー
+type Rule<'a, T> = Box<dyn Fn(&'a str) -> (&'a str, Result<T, Error>) + 'a>;
+// 読み飛ばすパーサー。残りの入力だけを返す
+type Skip<'a> = Box<dyn Fn(&'a str) -> &'a str + 'a>;
+
+/// 字句解析器
+///
+/// ルールとして登録したパーサーを使い、文字列を[Token]の列に変換します。
+///
+/// # 解析の仕様について
+/// - 先頭から順に、[skip][Lexer::skip]で登録したパーサーで空白などを読み飛ばします。
+/// - 次に、[rule][Lexer::rule]で登録した全てのパーサーを試し、**最も長く**一致したものをトークンにします。
+///   - 同じ長さで一致した場合は、先に登録したルールが優先されます。
+///   - 入力を消費しなかったルールは、一致しなかったものとして扱います。
+/// - どのルールにも一致しなかった場合は、1文字を[Unknown][TokenKind::Unknown]として次に進みます。
+///   解析は途中で止まらず、最後まで続けられます。
+///
+/// # ライフタイムについて
+/// [char][crate::parser::str_parser::char]などのパーサーは、作成した時点で入力のライフタイムが決まります。  
+/// そのため、[Lexer]は作成した時のライフタイムの入力しか解析できません。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::{
+///     combinator::Map,
+///     lexer::{Lexer, TokenKind},
+///     str_parser::{char, int, take_while1, trimer},
+/// };
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Tok<'a> {
+///     Num(i64),
+///     Ident(&'a str),
+///     Plus,
+/// }
+///
+/// let src = "x + 12 ? y";
+/// let lexer = Lexer::new()
+///     .rule(int.map(|r| r.map(Tok::Num)))
+///     .rule(take_while1(|c| c.is_alphabetic()).map(|r| r.map(Tok::Ident)))
+///     .rule(char('+').map(|r| r.map(|_| Tok::Plus)))
+///     .skip(trimer);
+///
+/// let tokens = lexer.tokenize(src);
+/// let kinds: Vec<_> = tokens.iter().map(|t| t.kind.clone()).collect();
+/// assert_eq!(
+///     kinds,
+///     [
+///         TokenKind::Value(Tok::Ident("x")),
+///         TokenKind::Value(Tok::Plus),
+///         TokenKind::Value(Tok::Num(12)),
+///         TokenKind::Unknown,
+///         TokenKind::Value(Tok::Ident("y")),
+///     ]
+/// );
+///
+/// // 一致しなかった部分はエラーとして取り出せる
+/// let errors: Vec<_> = tokens.iter().filter_map(|t| t.error()).collect();
+/// assert_eq!(errors[0].span().range(&src), 7..8);
+/// ```
+pub struct Lexer<'a, T> {
+    rules: Vec<Rule<'a, T>>,
+    skips: Vec<Skip<'a>>,
+}
+
+impl<'a, T> Lexer<'a, T> {
+    /// ルールが空の字句解析器を作成
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            skips: Vec::new(),
+        }
+    }
+    /// トークンにするルールを追加する
+    ///
+    /// 同じ長さで一致した場合は、先に追加したルールが優先されます。
+    pub fn rule(mut self, p: impl Parser<&'a str, Result<T, Error>> + 'a) -> Self {
+        self.rules.push(Box::new(p));
+        self
+    }
+    /// 読み飛ばすルールを追加する
+    ///
+    /// [trimer][crate::parser::str_parser::trimer]のように、結果を使わないパーサーを指定します。  
+    /// パーサーが入力を消費した場合に、その部分を読み飛ばします。結果は使われません。
+    pub fn skip<O>(mut self, p: impl Parser<&'a str, O> + 'a) -> Self {
+        self.skips.push(Box::new(move |i| p(i).0));
+        self
+    }
+    /// 文字列をトークン列に変換する
+    pub fn tokenize(&self, src: &'a str) -> Vec<Token<T>> {
+        let mut tokens = Vec::new();
+        // 一致しなかった部分の開始位置
+        let mut unknown: Option<&'a str> = None;
+        let mut rest = self.skip_all(src);
+        while !rest.is_empty() {
+            match self.longest_match(rest) {
+                Some((after, value)) => {
+                    if let Some(start) = unknown.take() {
+                        tokens.push(Token {
+                            kind: TokenKind::Unknown,
+                            span: Span::new(&start, &rest),
+                        });
+                    }
+                    tokens.push(Token {
+                        kind: TokenKind::Value(value),
+                        span: Span::new(&rest, &after),
+                    });
+                    rest = after;
+                }
+                None => {
+                    // 1文字進める
+                    unknown.get_or_insert(rest);
+                    let len = rest.chars().next().map_or(0, char::len_utf8);
+                    rest = &rest[len..];
+                }
+            }
+            // 読み飛ばした場合は、一致しなかった部分を区切る
+            let skipped = self.skip_all(rest);
+            if skipped.len() < rest.len() {
+                if let Some(start) = unknown.take() {
+                    tokens.push(Token {
+                        kind: TokenKind::Unknown,
+                        span: Span::new(&start, &rest),
+                    });
+                }
+            }
+            rest = skipped;
+        }
+        if let Some(start) = unknown {
+            tokens.push(Token {
+                kind: TokenKind::Unknown,
+                span: Span::new(&start, &rest),
+            });
+        }
+        tokens
+    }
+
+    // 読み飛ばすルールを、入力が消費されなくなるまで繰り返す
+    fn skip_all(&self, mut i: &'a str) -> &'a str {
+        loop {
+            let before = i.len();
+            for skip in &self.skips {
+                i = skip(i);
+            }
+            if i.len() >= before {
+                return i;
+            }
+        }
+    }
+
+    // 全てのルールを試し、最も長く一致したものを返す
+    fn longest_match(&self, i: &'a str) -> Option<(&'a str, T)> {
+        let mut best: Option<(&'a str, T)> = None;
+        for rule in &self.rules {
+            if let (after, Ok(value)) = rule(i) {
+                // 入力を消費しなかったものと、同じ長さのものは採用しない
+                let len = best.as_ref().map_or(i.len(), |(rest, _)| rest.len());
+                if after.len() < len {
+                    best = Some((after, value));
+                }
+            }
+        }
+        best
+    }
+}
+
+impl<T> Default for Lexer<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{combinator::*, str_parser::*};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Tok {
+        Eq,
+        EqEq,
+        Ident(String),
+        If,
+    }
+
+    fn lexer<'a>() -> Lexer<'a, Tok> {
+        Lexer::new()
+            .rule(string("if".to_string()).map(|r| r.map(|_| Tok::If)))
+            .rule(take_while1(|c| c.is_alphanumeric()).map(|r| r.map(|s| Tok::Ident(s.into()))))
+            .rule(char('=').map(|r| r.map(|_| Tok::Eq)))
+            .rule(string("==".to_string()).map(|r| r.map(|_| Tok::EqEq)))
+            .skip(trimer)
+    }
+
+    fn kinds(tokens: &[Token<Tok>]) -> Vec<TokenKind<Tok>> {
+        tokens.iter().map(|t| t.kind.clone()).collect()
+    }
+
+    // 最も長く一致したルールが採用される
+    #[test]
+    fn longest_match_test() {
+        let tokens = lexer().tokenize("a == b");
+        assert_eq!(
+            kinds(&tokens),
+            [
+                TokenKind::Value(Tok::Ident("a".into())),
+                TokenKind::Value(Tok::EqEq),
+                TokenKind::Value(Tok::Ident("b".into())),
+            ]
+        );
+        // "iffy"は"if"ではなく識別子になる
+        let tokens = lexer().tokenize("iffy");
+        assert_eq!(
+            kinds(&tokens),
+            [TokenKind::Value(Tok::Ident("iffy".into()))]
+        );
+    }
+
+    // 同じ長さの場合は、先に登録したルールが優先される
+    #[test]
+    fn tie_break_test() {
+        let tokens = lexer().tokenize("if");
+        assert_eq!(kinds(&tokens), [TokenKind::Value(Tok::If)]);
+    }
+
+    // 一致しない文字は、まとめてひとつのトークンになり、解析は続けられる
+    #[test]
+    fn unknown_test() {
+        let src = "a ?!# b ?";
+        let tokens = lexer().tokenize(src);
+        assert_eq!(
+            kinds(&tokens),
+            [
+                TokenKind::Value(Tok::Ident("a".into())),
+                TokenKind::Unknown,
+                TokenKind::Value(Tok::Ident("b".into())),
+                TokenKind::Unknown,
+            ]
+        );
+        assert_eq!(tokens[1].span.range(&src), 2..5);
+        assert_eq!(tokens[3].span.range(&src), 8..9);
+        assert_eq!(tokens[1].error().unwrap().kind(), &ErrorKind::UnknownToken);
+    }
+
+    // トークンの範囲
+    #[test]
+    fn span_test() {
+        let src = "  ｘ =";
+        let tokens = lexer().tokenize(src);
+        assert_eq!(tokens[0].span.range(&src), 2..5);
+        assert_eq!(tokens[1].span.range(&src), 6..7);
+    }
+}
@@ -0,0 +1,13 @@
+//! 文字列をトークン列に分割する、字句解析の機能を提供するモジュール
+//!
+//! 字句解析(トークナイズ)と構文解析を分けて行いたい場合に使います。  
+//! [Lexer]に[str_parser]などのパーサーをルールとして登録し、文字列を[Token]の列に変換します。  
+//! 作成したトークン列は[token_parser]のパーサーで解析できます。
+use super::*;
+
+#[allow(clippy::module_inception)]
+mod lexer;
+pub use lexer::*;
+
+mod token;
+pub use token::*;
@@ -0,0 +1,46 @@
+use super::*;
+
+/// 字句解析で得られたトークン
+///
+/// [Lexer::tokenize]が返す要素です。  
+/// トークンの値と、元の入力上の範囲を持ちます。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token<T> {
+    /// トークンの種類
+    pub kind: TokenKind<T>,
+    /// 元の入力上の範囲
+    pub span: Span,
+}
+
+/// トークンの種類
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind<T> {
+    /// ルールに一致した部分
+    Value(T),
+    /// どのルールにも一致しなかった部分
+    ///
+    /// 連続した一致しない文字は、ひとつのトークンにまとめられます。
+    Unknown,
+}
+
+impl<T> Token<T> {
+    /// トークンの値を取得する
+    ///
+    /// [Unknown][TokenKind::Unknown]の場合は[None]を返します。
+    pub fn value(&self) -> Option<&T> {
+        match &self.kind {
+            TokenKind::Value(v) => Some(v),
+            TokenKind::Unknown => None,
+        }
+    }
+    /// 一致しなかった部分を、エラーとして取得する
+    ///
+    /// [Unknown][TokenKind::Unknown]の場合は、
+    /// [kind][Error::kind]が [UnknownToken][ErrorKind::UnknownToken] の[エラー][Error]を返します。
+    pub fn error(&self) -> Option<Error> {
+        match &self.kind {
+            TokenKind::Value(_) => None,
+            TokenKind::Unknown => Some(Error::new(ErrorKind::UnknownToken, self.span)),
+        }
+    }
+}
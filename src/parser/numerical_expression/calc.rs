@@ -0,0 +1,113 @@
+use super::*;
+
+/// 計算時のエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalcError {
+    /// 0で割った
+    DivisionByZero,
+    /// 計算結果が[i64]に収まらない
+    Overflow,
+    /// べき乗の指数が負の数
+    NegativeExponent,
+}
+
+impl std::fmt::Display for CalcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalcError::DivisionByZero => write!(f, "division by zero"),
+            CalcError::Overflow => write!(f, "calculation overflowed"),
+            CalcError::NegativeExponent => write!(f, "exponent must not be negative"),
+        }
+    }
+}
+
+impl std::error::Error for CalcError {}
+
+impl Expr {
+    /// 数式を計算する
+    ///
+    /// # エラー時
+    /// - 0で割った場合は [DivisionByZero][CalcError::DivisionByZero] を返します。
+    /// - 途中の計算結果が[i64]に収まらない場合は [Overflow][CalcError::Overflow] を返します。
+    /// - べき乗の指数が負の場合は [NegativeExponent][CalcError::NegativeExponent] を返します。
+    ///
+    /// # Example
+    /// ```rust
+    /// use my_parser_project::parser::numerical_expression::{parser, CalcError};
+    ///
+    /// let (_, expr) = parser("10 / (5 - 5)");
+    /// assert_eq!(expr.unwrap().calc(), Err(CalcError::DivisionByZero));
+    /// ```
+    pub fn calc(&self) -> Result<i64, CalcError> {
+        match self {
+            Expr::Num(n) => Ok(*n),
+            Expr::Neg(e) => e.calc()?.checked_neg().ok_or(CalcError::Overflow),
+            Expr::BinOp { op, lhs, rhs } => {
+                let (l, r) = (lhs.calc()?, rhs.calc()?);
+                match op {
+                    BinOp::Add => l.checked_add(r).ok_or(CalcError::Overflow),
+                    BinOp::Sub => l.checked_sub(r).ok_or(CalcError::Overflow),
+                    BinOp::Mul => l.checked_mul(r).ok_or(CalcError::Overflow),
+                    BinOp::Div | BinOp::Rem if r == 0 => Err(CalcError::DivisionByZero),
+                    BinOp::Div => l.checked_div(r).ok_or(CalcError::Overflow),
+                    BinOp::Rem => l.checked_rem(r).ok_or(CalcError::Overflow),
+                    BinOp::Pow => pow(l, r),
+                }
+            }
+        }
+    }
+}
+
+// べき乗を計算する
+fn pow(base: i64, exp: i64) -> Result<i64, CalcError> {
+    if exp < 0 {
+        return Err(CalcError::NegativeExponent);
+    }
+    match (base, u32::try_from(exp)) {
+        (_, Ok(exp)) => base.checked_pow(exp).ok_or(CalcError::Overflow),
+        // 指数がu32に収まらなくても、結果が求まるもの
+        (0 | 1, Err(_)) => Ok(base),
+        (-1, Err(_)) => Ok(if exp % 2 == 0 { 1 } else { -1 }),
+        (_, Err(_)) => Err(CalcError::Overflow),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calc(src: &str) -> Result<i64, CalcError> {
+        let (_, expr) = parser(src);
+        expr.unwrap().calc()
+    }
+
+    // 計算できる
+    #[test]
+    fn calc_test() {
+        assert_eq!(calc("7 / 2"), Ok(3));
+        assert_eq!(calc("-7 % 3"), Ok(-1));
+        assert_eq!(calc("2 ^ 10"), Ok(1024));
+        assert_eq!(calc("1 ^ 99999999999"), Ok(1));
+        assert_eq!(calc("(-1) ^ 99999999999"), Ok(-1));
+    }
+
+    // 0で割る
+    #[test]
+    fn division_by_zero_test() {
+        assert_eq!(calc("1 / 0"), Err(CalcError::DivisionByZero));
+        assert_eq!(calc("1 % (2 - 2)"), Err(CalcError::DivisionByZero));
+    }
+
+    // 桁あふれ
+    #[test]
+    fn overflow_test() {
+        assert_eq!(calc("9223372036854775807 + 1"), Err(CalcError::Overflow));
+        assert_eq!(calc("-9223372036854775807 - 2"), Err(CalcError::Overflow));
+        assert_eq!(
+            calc("(-9223372036854775807 - 1) / -1"),
+            Err(CalcError::Overflow)
+        );
+        assert_eq!(calc("2 ^ 63"), Err(CalcError::Overflow));
+        assert_eq!(calc("2 ^ -1"), Err(CalcError::NegativeExponent));
+    }
+}
@@ -0,0 +1,55 @@
+/// 数式の構文木
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// 数値
+    Num(i64),
+    /// 符号反転 `-x`
+    Neg(Box<Expr>),
+    /// 二項演算 `lhs op rhs`
+    BinOp {
+        op: BinOp,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+    },
+}
+
+impl Expr {
+    /// 二項演算の構文木を作成
+    pub fn binary(op: BinOp, lhs: Expr, rhs: Expr) -> Self {
+        Self::BinOp {
+            op,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        }
+    }
+}
+
+/// 二項演算子
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinOp {
+    /// `+`
+    Add,
+    /// `-`
+    Sub,
+    /// `*`
+    Mul,
+    /// `/`
+    Div,
+    /// `%`
+    Rem,
+    /// `^`
+    Pow,
+}
+
+impl BinOp {
+    /// 演算子の結合力を`(左, 右)`で返す
+    ///
+    /// 値が大きいほど強く結合します。左の方が小さい場合は左結合、大きい場合は右結合になります。
+    pub fn binding_power(&self) -> (u8, u8) {
+        match self {
+            BinOp::Add | BinOp::Sub => (1, 2),
+            BinOp::Mul | BinOp::Div | BinOp::Rem => (3, 4),
+            BinOp::Pow => (7, 6),
+        }
+    }
+}
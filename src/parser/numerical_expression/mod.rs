@@ -0,0 +1,31 @@
+//! 四則演算などの数式を解析し、計算する機能を提供するモジュール
+//!
+//! [parser]で文字列を[Expr]の構文木に変換し、[calc][Expr::calc]で計算します。  
+//! 数値は[i64]の整数として扱います。
+//!
+//! # 対応している演算
+//! 優先順位の低い順に並べています。
+//! - `+` `-` : 加算、減算 (左結合)
+//! - `*` `/` `%` : 乗算、除算、剰余 (左結合)
+//! - `-x` : 符号反転
+//! - `^` : べき乗 (右結合)
+//! - `( )` : 括弧
+//!
+//! # Example
+//! ```rust
+//! use my_parser_project::parser::numerical_expression;
+//!
+//! let (rest, expr) = numerical_expression::parser("1 + 2 * (3 - 4) ^ 2");
+//! assert_eq!(expr.unwrap().calc(), Ok(3));
+//! assert_eq!(rest, "");
+//! ```
+use super::*;
+
+mod expr;
+pub use expr::*;
+
+mod calc;
+pub use calc::*;
+
+mod parse;
+pub use parse::*;
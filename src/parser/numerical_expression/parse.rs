@@ -0,0 +1,183 @@
+use super::*;
+use str_parser::{char, int, one_of, trimer};
+
+// 符号反転の結合力
+// 乗除算より強く、べき乗より弱い (-2^2 は -(2^2) になる)
+const PREFIX_BP: u8 = 5;
+
+/// 数式をパースする
+///
+/// 文字列から[Expr]の構文木を作成します。演算子や数値の前後の空白は読み飛ばします。
+///
+/// # パーサーの仕様について
+/// - 先頭から、数式として解析できる部分までを解析します。
+/// - 数値は[int][crate::parser::str_parser::int]と同じ形式で、`0x`などの接頭辞や`_`の区切りも使えます。
+/// ## 成功時
+/// - 構文木が結果として返されます。
+/// - 数式の後ろに解析できない部分がある場合は、残りの入力として返されます。
+/// ## エラー時
+/// - [エラー][crate::parser::Error]が返却され、入力は巻き戻されます。
+///   - 数値や`(`が必要な位置で見つからない場合は、[kind][crate::parser::Error::kind]が [Expected][crate::parser::ErrorKind::Expected] になります。
+///   - 数値が[i64]に収まらない場合は、 [IntegerOverflow][crate::parser::ErrorKind::IntegerOverflow] になります。
+///   - 閉じ括弧がない場合は、 [ParseCharError][crate::parser::ErrorKind::ParseCharError] になります。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::numerical_expression::{parser, BinOp, Expr};
+///
+/// let (_, expr) = parser("-2 ^ 2");
+/// let expr = expr.unwrap();
+/// assert_eq!(
+///     expr,
+///     Expr::Neg(Box::new(Expr::binary(BinOp::Pow, Expr::Num(2), Expr::Num(2))))
+/// );
+/// assert_eq!(expr.calc(), Ok(-4));
+///
+/// let (rest, expr) = parser("1 + * 2");
+/// assert_eq!(expr.unwrap_err().to_string(), "expected number, '-' or '('");
+/// assert_eq!(rest, "1 + * 2");
+/// ```
+pub fn parser(i: &str) -> (&str, Result<Expr, Error>) {
+    expr_bp(i, 0)
+}
+
+// min_bpより強く結合する演算子だけを読み進める
+fn expr_bp(i: &str, min_bp: u8) -> (&str, Result<Expr, Error>) {
+    let (mut rest, mut lhs) = match prefix(i) {
+        (rest, Ok(lhs)) => (rest, lhs),
+        (_, Err(e)) => return (i, Err(e)),
+    };
+    loop {
+        let (after_op, op) = operator(trimer(rest).0);
+        let op = match op {
+            Ok(op) => op,
+            Err(_) => break,
+        };
+        let (l_bp, r_bp) = op.binding_power();
+        if l_bp < min_bp {
+            break;
+        }
+        match expr_bp(after_op, r_bp) {
+            (after_rhs, Ok(rhs)) => {
+                lhs = Expr::binary(op, lhs, rhs);
+                rest = after_rhs;
+            }
+            (_, Err(e)) => return (i, Err(e)),
+        }
+    }
+    (rest, Ok(lhs))
+}
+
+// 数値、符号反転、括弧のいずれかを解析する
+fn prefix(i: &str) -> (&str, Result<Expr, Error>) {
+    let (rest, _) = trimer(i);
+    if let (rest, Ok(_)) = char('-')(rest) {
+        return match expr_bp(rest, PREFIX_BP) {
+            (rest, Ok(e)) => (rest, Ok(Expr::Neg(Box::new(e)))),
+            (_, Err(e)) => (i, Err(e)),
+        };
+    }
+    if let (rest, Ok(_)) = char('(')(rest) {
+        let (rest, e) = match expr_bp(rest, 0) {
+            (rest, Ok(e)) => (rest, e),
+            (_, Err(e)) => return (i, Err(e)),
+        };
+        return match char(')')(trimer(rest).0) {
+            (rest, Ok(_)) => (rest, Ok(e)),
+            (_, Err(err)) => (i, Err(err)),
+        };
+    }
+    if rest.starts_with(|c: char| c.is_ascii_digit()) {
+        return match int::<i64>(rest) {
+            (rest, Ok(n)) => (rest, Ok(Expr::Num(n))),
+            (_, Err(e)) => (i, Err(e)),
+        };
+    }
+    let err = Error::new(ErrorKind::Expected, str_parser::chars_span(rest, 1))
+        .with_expected("number")
+        .with_expected("'-'")
+        .with_expected("'('");
+    (i, Err(err))
+}
+
+// 二項演算子を解析する
+fn operator(i: &str) -> (&str, Result<BinOp, Error>) {
+    let (rest, c) = one_of("+-*/%^")(i);
+    let op = c.map(|c| match c {
+        '+' => BinOp::Add,
+        '-' => BinOp::Sub,
+        '*' => BinOp::Mul,
+        '/' => BinOp::Div,
+        '%' => BinOp::Rem,
+        _ => BinOp::Pow,
+    });
+    (rest, op)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn num(n: i64) -> Expr {
+        Expr::Num(n)
+    }
+
+    // 優先順位と結合性
+    #[test]
+    fn precedence_test() {
+        let (_, expr) = parser("1 - 2 - 3 * 4");
+        assert_eq!(
+            expr.unwrap(),
+            Expr::binary(
+                BinOp::Sub,
+                Expr::binary(BinOp::Sub, num(1), num(2)),
+                Expr::binary(BinOp::Mul, num(3), num(4)),
+            )
+        );
+        // べき乗は右結合
+        let (_, expr) = parser("2^3^2");
+        assert_eq!(
+            expr.unwrap(),
+            Expr::binary(BinOp::Pow, num(2), Expr::binary(BinOp::Pow, num(3), num(2)))
+        );
+    }
+
+    // 括弧と符号反転
+    #[test]
+    fn paren_test() {
+        let (rest, expr) = parser(" -( 1 + 2 ) * --3 ");
+        assert_eq!(expr.unwrap().calc(), Ok(-9));
+        assert_eq!(rest, " ");
+    }
+
+    // 数式の後ろの部分は残る
+    #[test]
+    fn rest_test() {
+        let (rest, expr) = parser("1 + 2 abc");
+        assert_eq!(expr.unwrap().calc(), Ok(3));
+        assert_eq!(rest, " abc");
+        // 演算子の後ろに数値がない場合は、エラーになり入力は全て巻き戻される
+        let (rest, expr) = parser("1 + 2 +");
+        assert!(expr.is_err());
+        assert_eq!(rest, "1 + 2 +");
+    }
+
+    // 閉じ括弧がない
+    #[test]
+    fn unclosed_test() {
+        let src = "(1 + 2";
+        let (rest, expr) = parser(src);
+        let err = expr.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::ParseCharError);
+        assert_eq!(err.expected(), ["')'"]);
+        assert_eq!(err.span().range(&src), 6..6);
+        assert_eq!(rest, src);
+    }
+
+    // 数値が大きすぎる
+    #[test]
+    fn overflow_test() {
+        let (_, expr) = parser("1 + 99999999999999999999");
+        assert_eq!(expr.unwrap_err().kind(), &ErrorKind::IntegerOverflow);
+    }
+}
@@ -6,11 +6,14 @@ use super::*;
 ///
 /// # パーサーの仕様について
 /// - 先頭のみ解析を行います。
+/// - マルチバイト文字(`'é'`や`'あ'`など)も扱えます。
 /// ## 成功時
 /// - 条件の文字が解析できたら、その文字が結果として返されます。
 /// ## エラー時
 /// - [エラー][super::error::Error]が返却されます。
 ///   - [kind][super::error::Error::kind]が [ParseCharError][super::ErrorKind::ParseCharError] になります。
+///   - [span][super::error::Error::span]は、見つかった文字を指します。
+///   - [expected][super::error::Error::expected]には、`'文字'`が記録されます。
 ///
 /// # Example
 /// ```rust
@@ -21,13 +24,11 @@ use super::*;
 /// assert_eq!(result, Ok('*'));
 /// ```
 pub fn char<'a>(c: char) -> impl Parser<&'a str, Result<char, Error>> {
-    move |i: &'a str| {
-        if i.chars().next() == Some(c) {
-            (&i[1..], Ok(c))
-        } else {
-            (&i, Err(Error::new(ErrorKind::ParseCharError)))
-        }
-    }
+    let label = format!("'{}'", c.escape_debug());
+    token_parser::satisfy_or(
+        move |x| x == c,
+        move |span| Error::new(ErrorKind::ParseCharError, span).with_expected(&label),
+    )
 }
 
 #[cfg(test)]
@@ -58,4 +59,31 @@ mod tests {
         // パースした文字（エラー）
         assert_eq!(result.unwrap_err().kind(), &ErrorKind::ParseCharError);
     }
+
+    // 正常系: マルチバイト文字
+    #[test]
+    fn multibyte_test() {
+        let base = "ｘé=1";
+        let (rest, result) = char('ｘ')(base);
+        assert_eq!(result, Ok('ｘ'));
+        let (rest, result) = char('é')(rest);
+        assert_eq!(result, Ok('é'));
+        assert_eq!(rest, "=1");
+        // 失敗した場合も、文字の途中で切らない
+        let (_, result) = char('=')("あ");
+        assert_eq!(result.unwrap_err().span().range(&"あ"), 0..3);
+    }
+
+    // 異常系: 失敗した位置を記録する
+    #[test]
+    fn error_span_test() {
+        let src = "ab\n+*";
+        let parser = char('*');
+        let (_, result) = parser(&src[3..]);
+        let err = result.unwrap_err();
+        // 見つかった'+'を指している
+        assert_eq!(err.span().range(&src), 3..4);
+        let pos = err.position(src);
+        assert_eq!((pos.offset, pos.line, pos.column), (3, 2, 1));
+    }
 }
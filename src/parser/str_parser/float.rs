@@ -0,0 +1,614 @@
+use super::*;
+
+/// [float]パーサーで扱える浮動小数点数型
+///
+/// `f32`, `f64` に実装されています。
+/// 10進数から2進数への変換に必要な、型ごとの定数を提供します。
+pub trait Float: Copy + std::ops::Mul<Output = Self> + std::ops::Div<Output = Self> {
+    /// 仮数部のビット数 (隠れビットを除く)
+    const MANTISSA_BITS: u32;
+    /// 指数部の最小値 (バイアス込みの指数が0になる値)
+    const MIN_EXPONENT: i32;
+    /// 無限大を表す、バイアス込みの指数
+    const INFINITE_POWER: i32;
+    /// 誤差なく表せる10の累乗の最大指数
+    const MAX_EXACT_POW10: usize;
+    /// 正の無限大
+    const INFINITY: Self;
+    /// NaN
+    const NAN: Self;
+    /// 0
+    const ZERO: Self;
+
+    /// ビット列から値を作成する
+    fn from_raw_bits(bits: u64) -> Self;
+    /// 整数から値を作成する (仮数部に収まる値のみ)
+    fn from_exact_u64(n: u64) -> Self;
+    /// 10の累乗を取得する (`MAX_EXACT_POW10`以下のみ)
+    fn exact_pow10(n: usize) -> Self;
+    /// 符号を反転する
+    fn negate(self) -> Self;
+}
+
+impl Float for f64 {
+    const MANTISSA_BITS: u32 = 52;
+    const MIN_EXPONENT: i32 = -1023;
+    const INFINITE_POWER: i32 = 0x7FF;
+    const MAX_EXACT_POW10: usize = 22;
+    const INFINITY: Self = f64::INFINITY;
+    const NAN: Self = f64::NAN;
+    const ZERO: Self = 0.0;
+
+    fn from_raw_bits(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+    fn from_exact_u64(n: u64) -> Self {
+        n as f64
+    }
+    fn exact_pow10(n: usize) -> Self {
+        const TABLE: [f64; 23] = [
+            1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
+            1e16, 1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+        ];
+        TABLE[n]
+    }
+    fn negate(self) -> Self {
+        -self
+    }
+}
+
+impl Float for f32 {
+    const MANTISSA_BITS: u32 = 23;
+    const MIN_EXPONENT: i32 = -127;
+    const INFINITE_POWER: i32 = 0xFF;
+    const MAX_EXACT_POW10: usize = 10;
+    const INFINITY: Self = f32::INFINITY;
+    const NAN: Self = f32::NAN;
+    const ZERO: Self = 0.0;
+
+    fn from_raw_bits(bits: u64) -> Self {
+        f32::from_bits(bits as u32)
+    }
+    fn from_exact_u64(n: u64) -> Self {
+        n as f32
+    }
+    fn exact_pow10(n: usize) -> Self {
+        const TABLE: [f32; 11] = [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+        TABLE[n]
+    }
+    fn negate(self) -> Self {
+        -self
+    }
+}
+
+/// [float]パーサーが受け付ける書式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatMode {
+    /// JSONと同じ書式
+    ///
+    /// - 符号は`-`のみ (`+1`は不可)
+    /// - 整数部は省略できず、`0`以外は`0`から始められない (`.5`は不可、`01`は`0`まで)
+    /// - 小数点の後ろには数字が必要 (`5.`は`5`まで)
+    /// - `inf`, `nan`は不可
+    Strict,
+    /// 一般的な書式
+    ///
+    /// - 符号は`+`, `-`
+    /// - 整数部と小数部のどちらかは省略可能 (`.5`, `5.`)
+    /// - `inf`, `infinity`, `nan` (大文字と小文字は区別しない)
+    Lenient,
+}
+
+/// 浮動小数点数でパースする
+///
+/// 浮動小数点数を条件に解析する [Parser][super::Parser] を**作成**します。
+/// 型引数で、結果の型を指定します (`float::<f64>`など)。
+///
+/// # パーサーの仕様について
+/// - 先頭から解析を行います。
+/// - `3.14`, `-1e-9`, `6.02E+23`のような、10進数と指数の書式を受け付けます。
+///   細かい書式は[FloatMode]で指定します。
+/// - 全ての入力に対して、最も近い値に正しく丸めます (同じ距離の場合は偶数側)。
+///   桁数の多い入力でも、二重に丸められることはありません。
+/// ## 成功時
+/// - 解析した値が、結果として返されます。
+///   - 型の範囲を超える値は無限大に、小さすぎる値は0になります。
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却され、入力は巻き戻されます。
+///   - [kind][super::error::Error::kind]が [ParseFloatError][super::ErrorKind::ParseFloatError] になります。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::str_parser::{float, FloatMode};
+///
+/// let parser = float::<f64>(FloatMode::Lenient);
+/// assert_eq!(parser("3.14abc"), ("abc", Ok(3.14)));
+/// assert_eq!(parser("-1e-9"), ("", Ok(-1e-9)));
+/// assert_eq!(parser("inf"), ("", Ok(f64::INFINITY)));
+/// assert!(parser("NaN").1.unwrap().is_nan());
+///
+/// let parser = float::<f32>(FloatMode::Strict);
+/// assert_eq!(parser("0.5"), ("", Ok(0.5)));
+/// assert!(parser(".5").1.is_err());
+/// ```
+pub fn float<'a, F: Float>(mode: FloatMode) -> impl Parser<&'a str, Result<F, Error>> {
+    move |i: &'a str| match scan(i, mode) {
+        Some((rest, value)) => (rest, Ok(value)),
+        None => {
+            let err =
+                Error::new(ErrorKind::ParseFloatError, chars_span(i, 1)).with_expected("float");
+            (i, Err(err))
+        }
+    }
+}
+
+// 書式に従って入力を読み進め、値を求める
+fn scan<F: Float>(i: &str, mode: FloatMode) -> Option<(&str, F)> {
+    let bytes = i.as_bytes();
+    // 数字が続く位置まで進める
+    let digits_end = |mut p: usize| {
+        while bytes.get(p).is_some_and(u8::is_ascii_digit) {
+            p += 1;
+        }
+        p
+    };
+
+    // 符号
+    let (negative, mut p) = match (bytes.first(), mode) {
+        (Some(b'-'), _) => (true, 1),
+        (Some(b'+'), FloatMode::Lenient) => (false, 1),
+        _ => (false, 0),
+    };
+    let sign = |v: F| if negative { v.negate() } else { v };
+
+    // inf, nan
+    if mode == FloatMode::Lenient {
+        for (word, value) in [
+            ("infinity", F::INFINITY),
+            ("inf", F::INFINITY),
+            ("nan", F::NAN),
+        ] {
+            if i.get(p..p + word.len())
+                .is_some_and(|s| s.eq_ignore_ascii_case(word))
+            {
+                return Some((&i[p + word.len()..], sign(value)));
+            }
+        }
+    }
+
+    // 整数部
+    let int_start = p;
+    p = match (mode, bytes.get(p)) {
+        // 0から始まる場合は、0のみが整数部になる
+        (FloatMode::Strict, Some(b'0')) => p + 1,
+        _ => digits_end(p),
+    };
+    let int_digits = &bytes[int_start..p];
+    if mode == FloatMode::Strict && int_digits.is_empty() {
+        return None;
+    }
+
+    // 小数部
+    let mut frac_digits: &[u8] = &[];
+    if bytes.get(p) == Some(&b'.') {
+        let end = digits_end(p + 1);
+        let accept = match mode {
+            FloatMode::Strict => end > p + 1,
+            FloatMode::Lenient => end > p + 1 || !int_digits.is_empty(),
+        };
+        if accept {
+            frac_digits = &bytes[p + 1..end];
+            p = end;
+        }
+    }
+    if int_digits.is_empty() && frac_digits.is_empty() {
+        return None;
+    }
+
+    // 指数部
+    let mut exp: i64 = 0;
+    if matches!(bytes.get(p), Some(b'e' | b'E')) {
+        let mut q = p + 1;
+        let exp_negative = bytes.get(q) == Some(&b'-');
+        if matches!(bytes.get(q), Some(b'-' | b'+')) {
+            q += 1;
+        }
+        let end = digits_end(q);
+        if end > q {
+            // 大きすぎる指数は、結果が0か無限大になるので飽和させる
+            exp = bytes[q..end].iter().fold(0i64, |acc, d| {
+                acc.saturating_mul(10).saturating_add((d - b'0') as i64)
+            });
+            if exp_negative {
+                exp = -exp;
+            }
+            p = end;
+        }
+    }
+
+    let value = Decimal::new(int_digits, frac_digits, exp).into_float::<F>();
+    Some((&i[p..], sign(value)))
+}
+
+// 10進数の値を保持する構造体
+// 値は 0.d1d2d3... × 10^decimal_point を表す
+// 2進数への変換は、2の累乗でシフトを繰り返して行う (Simple Decimal Conversion)
+struct Decimal {
+    // 各桁の数字 (先頭と末尾の0は取り除く)
+    digits: Vec<u8>,
+    decimal_point: i32,
+    // 保持しきれずに切り捨てた桁に、0以外が含まれているか
+    truncated: bool,
+}
+
+impl Decimal {
+    // 保持する最大桁数
+    // 正しく丸めるために必要な桁数は、f64でも768桁以下であることが知られている
+    const MAX_DIGITS: usize = 768;
+    // 小数点の位置が、これを超えると0か無限大になる
+    const DECIMAL_POINT_RANGE: i32 = 2047;
+
+    fn new(int_digits: &[u8], frac_digits: &[u8], exp: i64) -> Self {
+        let mut decimal_point = int_digits.len() as i64;
+        let mut digits = Vec::new();
+        let mut truncated = false;
+        for d in int_digits.iter().chain(frac_digits) {
+            let d = d - b'0';
+            if digits.is_empty() && d == 0 {
+                // 先頭の0は取り除く
+                decimal_point -= 1;
+            } else if digits.len() < Self::MAX_DIGITS {
+                digits.push(d);
+            } else if d != 0 {
+                truncated = true;
+            }
+        }
+        let decimal_point = decimal_point.saturating_add(exp);
+        let decimal_point = decimal_point.clamp(
+            -(Self::DECIMAL_POINT_RANGE as i64) - 1,
+            Self::DECIMAL_POINT_RANGE as i64 + 1,
+        ) as i32;
+        let mut d = Self {
+            digits,
+            decimal_point,
+            truncated,
+        };
+        d.trim();
+        d
+    }
+
+    // 末尾の0を取り除く
+    fn trim(&mut self) {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+    }
+
+    // 値を2^shift倍する
+    fn left_shift(&mut self, shift: u32) {
+        if self.digits.is_empty() {
+            return;
+        }
+        let len = self.digits.len();
+        let mut out = Vec::with_capacity(len + 19);
+        let mut n: u64 = 0;
+        // 下の桁から順に掛け、繰り上がりを伝える
+        for &d in self.digits.iter().rev() {
+            n += (d as u64) << shift;
+            out.push((n % 10) as u8);
+            n /= 10;
+        }
+        while n > 0 {
+            out.push((n % 10) as u8);
+            n /= 10;
+        }
+        out.reverse();
+        self.decimal_point += (out.len() - len) as i32;
+        if out.len() > Self::MAX_DIGITS {
+            self.truncated |= out[Self::MAX_DIGITS..].iter().any(|d| *d != 0);
+            out.truncate(Self::MAX_DIGITS);
+        }
+        self.digits = out;
+        self.trim();
+    }
+
+    // 値を2^shiftで割る
+    fn right_shift(&mut self, shift: u32) {
+        let mut read = 0;
+        let mut n: u64 = 0;
+        // 割れる大きさになるまで、上の桁から読み込む
+        while (n >> shift) == 0 {
+            if read < self.digits.len() {
+                n = 10 * n + self.digits[read] as u64;
+                read += 1;
+            } else if n == 0 {
+                return;
+            } else {
+                while (n >> shift) == 0 {
+                    n *= 10;
+                    read += 1;
+                }
+                break;
+            }
+        }
+        self.decimal_point -= read as i32 - 1;
+        if self.decimal_point < -Self::DECIMAL_POINT_RANGE {
+            self.digits.clear();
+            self.decimal_point = 0;
+            self.truncated = false;
+            return;
+        }
+        let mask = (1u64 << shift) - 1;
+        let mut out = Vec::with_capacity(self.digits.len() + shift as usize);
+        while read < self.digits.len() {
+            out.push((n >> shift) as u8);
+            n = 10 * (n & mask) + self.digits[read] as u64;
+            read += 1;
+        }
+        while n > 0 {
+            let d = (n >> shift) as u8;
+            n = 10 * (n & mask);
+            if out.len() < Self::MAX_DIGITS {
+                out.push(d);
+            } else if d > 0 {
+                self.truncated = true;
+            }
+        }
+        self.digits = out;
+        self.trim();
+    }
+
+    // 整数部を、最も近い整数に丸めて取得する (同じ距離の場合は偶数側)
+    fn round(&self) -> u64 {
+        if self.digits.is_empty() || self.decimal_point < 0 {
+            return 0;
+        } else if self.decimal_point > 18 {
+            return u64::MAX;
+        }
+        let dp = self.decimal_point as usize;
+        let mut n = 0u64;
+        for i in 0..dp {
+            n = n * 10 + self.digits.get(i).copied().unwrap_or(0) as u64;
+        }
+        let round_up = match self.digits.get(dp) {
+            // ちょうど半分
+            Some(5) if dp + 1 == self.digits.len() => {
+                self.truncated || (dp > 0 && self.digits[dp - 1] % 2 == 1)
+            }
+            Some(d) => *d >= 5,
+            None => false,
+        };
+        n + round_up as u64
+    }
+
+    // 桁数が少ない場合は、浮動小数点数の演算1回で正確に求められる
+    fn fast_path<F: Float>(&self) -> Option<F> {
+        if self.digits.len() > 19 || self.truncated {
+            return None;
+        }
+        let mantissa = self.digits.iter().fold(0u64, |n, d| n * 10 + *d as u64);
+        if mantissa >> (F::MANTISSA_BITS + 1) != 0 {
+            return None;
+        }
+        let exp = self.decimal_point as i64 - self.digits.len() as i64;
+        let pow = exp.unsigned_abs() as usize;
+        if pow > F::MAX_EXACT_POW10 {
+            return None;
+        }
+        let value = F::from_exact_u64(mantissa);
+        match exp < 0 {
+            true => Some(value / F::exact_pow10(pow)),
+            false => Some(value * F::exact_pow10(pow)),
+        }
+    }
+
+    fn into_float<F: Float>(mut self) -> F {
+        // 一度にシフトできる最大ビット数
+        const MAX_SHIFT: u32 = 60;
+        // 10^n を超える最小の2の累乗の指数 (n < 19)
+        const POWERS: [u32; 19] = [
+            0, 3, 6, 9, 13, 16, 19, 23, 26, 29, 33, 36, 39, 43, 46, 49, 53, 56, 59,
+        ];
+        let get_shift = |n: usize| POWERS.get(n).copied().unwrap_or(MAX_SHIFT);
+        let from_parts = |mantissa: u64, power2: i32| {
+            F::from_raw_bits(mantissa | ((power2 as u64) << F::MANTISSA_BITS))
+        };
+
+        if self.digits.is_empty() || self.decimal_point < -324 {
+            return F::ZERO;
+        } else if self.decimal_point >= 310 {
+            return F::INFINITY;
+        }
+        if let Some(value) = self.fast_path() {
+            return value;
+        }
+
+        // 値が [1/2, 1) に収まるようにシフトする
+        let mut exp2 = 0i32;
+        while self.decimal_point > 0 {
+            let shift = get_shift(self.decimal_point as usize);
+            self.right_shift(shift);
+            if self.decimal_point < -Self::DECIMAL_POINT_RANGE {
+                return F::ZERO;
+            }
+            exp2 += shift as i32;
+        }
+        while self.decimal_point <= 0 {
+            let shift = match self.decimal_point {
+                0 => match self.digits[0] {
+                    5.. => break,
+                    0 | 1 => 2,
+                    _ => 1,
+                },
+                dp => get_shift((-dp) as usize),
+            };
+            self.left_shift(shift);
+            if self.decimal_point > Self::DECIMAL_POINT_RANGE {
+                return F::INFINITY;
+            }
+            exp2 -= shift as i32;
+        }
+        // 2進数の仮数部は [1, 2) なので、指数を合わせる
+        exp2 -= 1;
+        // 非正規化数の場合は、指数が最小値になるまでシフトする
+        while F::MIN_EXPONENT + 1 > exp2 {
+            let n = ((F::MIN_EXPONENT + 1 - exp2) as u32).min(MAX_SHIFT);
+            self.right_shift(n);
+            exp2 += n as i32;
+        }
+        if exp2 - F::MIN_EXPONENT >= F::INFINITE_POWER {
+            return F::INFINITY;
+        }
+        // 仮数部のビット数分シフトして、丸める
+        self.left_shift(F::MANTISSA_BITS + 1);
+        let mut mantissa = self.round();
+        if mantissa >= (1u64 << (F::MANTISSA_BITS + 1)) {
+            // 丸めによって桁が増えたので、もう一度シフトする
+            self.right_shift(1);
+            exp2 += 1;
+            mantissa = self.round();
+            if exp2 - F::MIN_EXPONENT >= F::INFINITE_POWER {
+                return F::INFINITY;
+            }
+        }
+        let mut power2 = exp2 - F::MIN_EXPONENT;
+        if mantissa < (1u64 << F::MANTISSA_BITS) {
+            // 非正規化数
+            power2 -= 1;
+        }
+        mantissa &= (1u64 << F::MANTISSA_BITS) - 1;
+        from_parts(mantissa, power2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // std の変換結果とビット単位で一致するか確認する
+    fn check(s: &str) {
+        let (rest, result) = float::<f64>(FloatMode::Lenient)(s);
+        assert_eq!(rest, "", "{}", s);
+        assert_eq!(
+            result.unwrap().to_bits(),
+            s.parse::<f64>().unwrap().to_bits(),
+            "f64: {}",
+            s
+        );
+        let (_, result) = float::<f32>(FloatMode::Lenient)(s);
+        assert_eq!(
+            result.unwrap().to_bits(),
+            s.parse::<f32>().unwrap().to_bits(),
+            "f32: {}",
+            s
+        );
+    }
+
+    // 正常系: 一般的な値
+    #[test]
+    fn success_test() {
+        let parser = float::<f64>(FloatMode::Strict);
+        assert_eq!(parser("2.75,"), (",", Ok(2.75)));
+        assert_eq!(parser("-0.5e3"), ("", Ok(-500.0)));
+        assert_eq!(parser("1E+2x"), ("x", Ok(100.0)));
+        assert_eq!(parser("42"), ("", Ok(42.0)));
+        assert!(parser("-0").1.unwrap().is_sign_negative());
+    }
+
+    // 丸めが難しい値
+    #[test]
+    fn rounding_test() {
+        for s in [
+            "0.1",
+            "0.3",
+            "9007199254740993",
+            "9007199254740995",
+            "2.2250738585072011e-308",
+            "2.2250738585072014e-308",
+            "4.9e-324",
+            "2.4703282292062327e-324",
+            "2.4703282292062328e-324",
+            "1.7976931348623157e308",
+            "1.7976931348623158e308",
+            "1.7976931348623159e308",
+            "1e400",
+            "1e-400",
+            "1.00000005960464477539062499",
+            "1.00000005960464477539062500",
+            "1.00000005960464477539062501",
+            "3.4028235677973366e38",
+            "1.401298464324817e-45",
+            "7.0064923216240854e-46",
+            "7.0064923216240862e-46",
+            "0.1000000000000000055511151231257827021181583404541015625",
+            "0.1000000000000000055511151231257827021181583404541015624",
+            "123456789012345678901234567890e-10",
+            "0.000000000000000000000000000000000000000000001e45",
+        ] {
+            check(s);
+        }
+    }
+
+    // 疑似乱数で作った値を std と比べる
+    #[test]
+    fn random_test() {
+        let mut seed: u64 = 0x2545_F491_4F6C_DD1D;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+        let mut s = String::new();
+        for _ in 0..5000 {
+            s.clear();
+            let digits = 1 + next() % 30;
+            let point = next() % (digits + 1);
+            for n in 0..digits {
+                if n == point && n > 0 {
+                    s.push('.');
+                }
+                s.push(char::from(b'0' + (next() % 10) as u8));
+            }
+            let exp = (next() % 700) as i64 - 350;
+            s.push_str(&format!("e{}", exp));
+            check(&s);
+        }
+    }
+
+    // 書式の違い
+    #[test]
+    fn mode_test() {
+        let strict = float::<f64>(FloatMode::Strict);
+        let lenient = float::<f64>(FloatMode::Lenient);
+        // 先頭の+
+        assert!(strict("+1").1.is_err());
+        assert_eq!(lenient("+1"), ("", Ok(1.0)));
+        // 整数部の省略
+        assert!(strict(".5").1.is_err());
+        assert_eq!(lenient(".5"), ("", Ok(0.5)));
+        // 小数部の省略
+        assert_eq!(strict("5."), (".", Ok(5.0)));
+        assert_eq!(lenient("5."), ("", Ok(5.0)));
+        // 先頭の0
+        assert_eq!(strict("01"), ("1", Ok(0.0)));
+        assert_eq!(lenient("01"), ("", Ok(1.0)));
+        // inf, nan
+        assert!(strict("inf").1.is_err());
+        assert_eq!(lenient("-Infinity"), ("", Ok(f64::NEG_INFINITY)));
+        assert_eq!(lenient("infx"), ("x", Ok(f64::INFINITY)));
+        // 指数の数字がない場合は、指数部を読まない
+        assert_eq!(strict("1e"), ("e", Ok(1.0)));
+        assert_eq!(lenient("1e+x"), ("e+x", Ok(1.0)));
+    }
+
+    // 異常系: 数字がない
+    #[test]
+    fn failure_test() {
+        for s in ["abc", "-", ".", "-.e1", "+"] {
+            let (rest, result) = float::<f64>(FloatMode::Lenient)(s);
+            assert_eq!(result.unwrap_err().kind(), &ErrorKind::ParseFloatError);
+            assert_eq!(rest, s);
+        }
+    }
+}
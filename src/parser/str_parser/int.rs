@@ -0,0 +1,194 @@
+use super::*;
+
+/// [int]パーサーで扱える整数型
+///
+/// `i8`〜`i128`, `isize`, `u8`〜`u128`, `usize` に実装されています。
+pub trait Integer: Copy {
+    /// 符号付きの型かどうか
+    const SIGNED: bool;
+    /// 0
+    const ZERO: Self;
+    /// 値を基数倍し、1桁追加する
+    ///
+    /// 負の数の場合は、桁を引き算します。  
+    /// 桁あふれした場合は[None]を返します。
+    fn checked_push(self, digit: u32, radix: u32, negative: bool) -> Option<Self>;
+}
+
+// 整数型にIntegerを実装するマクロ
+macro_rules! impl_integer {
+    ($signed:expr => $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const SIGNED: bool = $signed;
+                const ZERO: Self = 0;
+                fn checked_push(self, digit: u32, radix: u32, negative: bool) -> Option<Self> {
+                    // 基数と桁は16以下なので、どの型にも収まる
+                    let v = self.checked_mul(radix as $t)?;
+                    match negative {
+                        true => v.checked_sub(digit as $t),
+                        false => v.checked_add(digit as $t),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(true => i8, i16, i32, i64, i128, isize);
+impl_integer!(false => u8, u16, u32, u64, u128, usize);
+
+/// 整数でパースする
+///
+/// 整数を条件に解析する [Parser][super::Parser] です。  
+/// 型引数で、結果の整数型を指定します (`int::<i32>`など)。
+///
+/// # パーサーの仕様について
+/// - 先頭から解析を行います。
+/// - 先頭の`+`を受け付けます。符号付きの型では`-`も受け付けます。
+/// - `0x`(16進数), `0o`(8進数), `0b`(2進数)の接頭辞を受け付けます。
+/// - 数字の間の`_`は区切り文字として読み飛ばします (`1_000`など)。
+/// ## 成功時
+/// - 解析した整数が、結果として返されます。
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却され、入力は巻き戻されます。
+///   - 数字が見つからなかった場合は、[kind][super::error::Error::kind]が [ParseIntegerError][super::ErrorKind::ParseIntegerError] になります。
+///   - 型に収まらなかった場合は、[kind][super::error::Error::kind]が [IntegerOverflow][super::ErrorKind::IntegerOverflow] になります。
+///     [span][super::error::Error::span]は、符号を含めた整数全体を指します。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::{str_parser::int, ErrorKind};
+///
+/// assert_eq!(int::<i32>("-123abc"), ("abc", Ok(-123)));
+/// assert_eq!(int::<u8>("0xff"), ("", Ok(255)));
+/// assert_eq!(int::<u32>("1_000_000"), ("", Ok(1_000_000)));
+/// assert_eq!(int::<i8>("-0b1000_0000"), ("", Ok(-128)));
+///
+/// let (rest, result) = int::<u8>("256");
+/// assert_eq!(result.unwrap_err().kind(), &ErrorKind::IntegerOverflow);
+/// assert_eq!(rest, "256");
+/// ```
+pub fn int<T: Integer>(i: &str) -> (&str, Result<T, Error>) {
+    // 符号
+    let (negative, body) = match i.as_bytes().first() {
+        Some(b'-') if T::SIGNED => (true, &i[1..]),
+        Some(b'+') => (false, &i[1..]),
+        _ => (false, i),
+    };
+    // 接頭辞
+    let (radix, label, digits) = match body.get(..2) {
+        Some("0x" | "0X") => (16, "hexadecimal digit", &body[2..]),
+        Some("0o" | "0O") => (8, "octal digit", &body[2..]),
+        Some("0b" | "0B") => (2, "binary digit", &body[2..]),
+        _ => (10, "integer", body),
+    };
+
+    let mut chars = digits.char_indices().peekable();
+    let mut value = Some(T::ZERO);
+    let mut end = None;
+    while let Some((p, c)) = chars.next() {
+        match c.to_digit(radix) {
+            Some(d) => {
+                // 桁あふれした後も、整数の終わりまでは読み進める
+                value = value.and_then(|v| v.checked_push(d, radix, negative));
+                end = Some(p + c.len_utf8());
+            }
+            // 数字の間の'_'は読み飛ばす
+            None if c == '_' && end.is_some() => match chars.peek() {
+                Some((_, next)) if next.is_digit(radix) => {}
+                _ => break,
+            },
+            None => break,
+        }
+    }
+
+    let Some(end) = end else {
+        let err =
+            Error::new(ErrorKind::ParseIntegerError, chars_span(digits, 1)).with_expected(label);
+        return (i, Err(err));
+    };
+    let rest = &digits[end..];
+    match value {
+        Some(v) => (rest, Ok(v)),
+        None => {
+            let err =
+                Error::new(ErrorKind::IntegerOverflow, Span::new(&i, &rest)).with_expected(label);
+            (i, Err(err))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 正常系: 符号付き
+    #[test]
+    fn signed_test() {
+        assert_eq!(int::<i64>("-42;"), (";", Ok(-42)));
+        assert_eq!(int::<i64>("+42;"), (";", Ok(42)));
+        assert_eq!(
+            int::<i128>("-170141183460469231731687303715884105728"),
+            ("", Ok(i128::MIN))
+        );
+    }
+
+    // 正常系: 全ての型の最大値と最小値
+    #[test]
+    fn bounds_test() {
+        macro_rules! check {
+            ($($t:ty),*) => {
+                $(
+                    let max = <$t>::MAX.to_string();
+                    let min = <$t>::MIN.to_string();
+                    assert_eq!(int::<$t>(&max), ("", Ok(<$t>::MAX)));
+                    assert_eq!(int::<$t>(&min), ("", Ok(<$t>::MIN)));
+                )*
+            };
+        }
+        check!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    }
+
+    // 正常系: 接頭辞
+    #[test]
+    fn radix_test() {
+        assert_eq!(int::<u32>("0xDead_Beef"), ("", Ok(0xdead_beef)));
+        assert_eq!(int::<i32>("-0o17 "), (" ", Ok(-0o17)));
+        assert_eq!(int::<u8>("0b1010z"), ("z", Ok(0b1010)));
+    }
+
+    // 正常系: '_'は数字の間だけ読み飛ばす
+    #[test]
+    fn separator_test() {
+        assert_eq!(int::<u32>("1_000_"), ("_", Ok(1000)));
+        assert_eq!(int::<u32>("1__0"), ("__0", Ok(1)));
+    }
+
+    // 異常系: 数字がない
+    #[test]
+    fn no_digits_test() {
+        let (rest, result) = int::<u8>("-1");
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::ParseIntegerError);
+        assert_eq!(rest, "-1");
+
+        let src = "0xg";
+        let (rest, result) = int::<u8>(src);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::ParseIntegerError);
+        assert_eq!(err.expected(), ["hexadecimal digit"]);
+        assert_eq!(err.span().range(&src), 2..3);
+        assert_eq!(rest, "0xg");
+    }
+
+    // 異常系: 桁あふれ
+    #[test]
+    fn overflow_test() {
+        let src = "x=-129;";
+        let (rest, result) = int::<i8>(&src[2..]);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::IntegerOverflow);
+        assert_eq!(err.span().range(&src), 2..6);
+        assert_eq!(rest, "-129;");
+    }
+}
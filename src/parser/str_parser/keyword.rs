@@ -0,0 +1,241 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use super::*;
+
+/// [keyword_table]の動作を指定する
+///
+/// [Default]では、大文字と小文字を区別し、単語の境界を確認します。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeywordOptions {
+    /// 大文字と小文字を区別せずに比較する
+    pub case_insensitive: bool,
+    /// キーワードの直後が単語の続き(英数字や`_`)であれば、一致しないものとする
+    ///
+    /// `say`が`saying`に一致しないようにするために使います。  
+    /// キーワードの最後の文字が記号の場合は確認しません。
+    pub word_boundary: bool,
+}
+
+impl Default for KeywordOptions {
+    fn default() -> Self {
+        Self {
+            case_insensitive: false,
+            word_boundary: true,
+        }
+    }
+}
+
+/// キーワードの一覧からパースする
+///
+/// `(キーワード, 値)`の一覧を条件に解析する [Parser][super::Parser] を**作成**します。  
+/// REPLのコマンドのように、多数のキーワードから一つを選ぶ時に使います。
+///
+/// # パーサーの仕様について
+/// - 先頭から解析を行います。
+/// - 一致するキーワードが複数ある場合は、最も長いものが選ばれます (`exit`と`exitall`など)。
+/// - キーワードは文字数ごとにまとめて検索するため、キーワードが多くても高速に動作します。
+/// - 同じキーワードが複数ある場合は、先に書かれたものが使われます。
+/// ## 成功時
+/// - 一致したキーワードに対応する値が、結果として返されます。
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseKeywordError][super::ErrorKind::ParseKeywordError] になります。
+///   - [span][super::error::Error::span]は、見つかった単語を指します。
+///   - [expected][super::error::Error::expected]には、全てのキーワードが記録されます。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::str_parser::{keyword_table, KeywordOptions};
+///
+/// let table = [("exit", 1), ("exitall", 2), ("say", 3)];
+/// let parser = keyword_table(&table, KeywordOptions::default());
+///
+/// assert_eq!(parser("exitall now"), (" now", Ok(2)));
+/// assert_eq!(parser("exit now"), (" now", Ok(1)));
+/// // 単語の途中では一致しない
+/// assert!(parser("saying").1.is_err());
+///
+/// // 大文字と小文字を区別しない
+/// let options = KeywordOptions { case_insensitive: true, ..Default::default() };
+/// let parser = keyword_table(&table, options);
+/// assert_eq!(parser("SAY hi"), (" hi", Ok(3)));
+/// ```
+pub fn keyword_table<T: Clone>(
+    table: &[(&str, T)],
+    options: KeywordOptions,
+) -> impl for<'a> Parser<&'a str, Result<T, Error>> {
+    let normalize = move |s: &str| match options.case_insensitive {
+        true => fold_case(s).collect(),
+        false => s.to_string(),
+    };
+    // 文字数ごとにキーワードをまとめ、短い順に並べる
+    let mut groups: Vec<(usize, HashMap<String, T>)> = Vec::new();
+    for (keyword, value) in table {
+        let len = keyword.chars().count();
+        let n = match groups.iter().position(|(l, _)| *l == len) {
+            Some(n) => n,
+            None => {
+                groups.push((len, HashMap::new()));
+                groups.len() - 1
+            }
+        };
+        groups[n]
+            .1
+            .entry(normalize(keyword))
+            .or_insert_with(|| value.clone());
+    }
+    groups.sort_by_key(|(len, _)| *len);
+    // 失敗する度に作り直さないよう、重複を除いて共有しておく
+    let mut expected: Vec<String> = Vec::new();
+    for (keyword, _) in table {
+        let k = format!("'{}'", keyword.escape_debug());
+        if !expected.contains(&k) {
+            expected.push(k);
+        }
+    }
+    let expected: Rc<[String]> = expected.into();
+    let groups = Rc::new(groups);
+    // 大文字と小文字を区別しない場合に、小文字にした入力を入れておくバッファ
+    let folded = RefCell::new(String::new());
+
+    move |i: &str| {
+        // 先頭から1文字ずつ進め、文字数が一致するまとまりだけを検索する
+        // 短い順に調べるので、最後に一致したものが最も長いキーワードになる
+        let mut found = None;
+        let mut groups = groups.iter().peekable();
+        let mut folded = folded.borrow_mut();
+        folded.clear();
+        let mut folded_end = 0;
+        let ends = i
+            .char_indices()
+            .map(|(p, _)| p)
+            .chain(std::iter::once(i.len()));
+        for (n, end) in ends.enumerate() {
+            let Some((len, keywords)) = groups.peek() else {
+                break;
+            };
+            if n < *len {
+                continue;
+            }
+            groups.next();
+            let word = &i[..end];
+            let value = match options.case_insensitive {
+                true => {
+                    // 前回の続きから小文字にする
+                    folded.extend(fold_case(&i[folded_end..end]));
+                    folded_end = end;
+                    keywords.get(folded.as_str())
+                }
+                false => keywords.get(word),
+            };
+            let Some(value) = value else {
+                continue;
+            };
+            // 単語の途中であれば一致しない
+            let rest = &i[end..];
+            if options.word_boundary
+                && word.chars().next_back().is_some_and(is_word_char)
+                && rest.chars().next().is_some_and(is_word_char)
+            {
+                continue;
+            }
+            found = Some((rest, value));
+        }
+        if let Some((rest, value)) = found {
+            return (rest, Ok(value.clone()));
+        }
+        // 見つかった単語を指してエラーにする
+        let word = i.chars().take_while(|c| is_word_char(*c)).count().max(1);
+        let err = Error::new(ErrorKind::ParseKeywordError, chars_span(i, word))
+            .with_expected_list(&expected);
+        (i, Err(err))
+    }
+}
+
+// 大文字と小文字を区別せずに比較するため、小文字にする
+fn fold_case(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.chars().flat_map(char::to_lowercase)
+}
+
+// 単語を構成する文字かどうか
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 最も長いキーワードが選ばれる
+    #[test]
+    fn longest_match_test() {
+        let table = [("+", 1), ("+=", 2), ("++", 3)];
+        let parser = keyword_table(&table, KeywordOptions::default());
+        assert_eq!(parser("+=1"), ("1", Ok(2)));
+        assert_eq!(parser("++"), ("", Ok(3)));
+        assert_eq!(parser("+1"), ("1", Ok(1)));
+    }
+
+    // 単語の境界を確認する
+    #[test]
+    fn word_boundary_test() {
+        let table = [("say", 1), ("exit", 2)];
+        let parser = keyword_table(&table, KeywordOptions::default());
+        let (rest, result) = parser("saying");
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::ParseKeywordError);
+        assert_eq!(err.span().range(&"saying"), 0..6);
+        assert_eq!(err.expected(), ["'say'", "'exit'"]);
+        assert_eq!(rest, "saying");
+
+        // 境界の確認をしない
+        let options = KeywordOptions {
+            word_boundary: false,
+            ..Default::default()
+        };
+        let parser = keyword_table(&table, options);
+        assert_eq!(parser("saying"), ("ing", Ok(1)));
+    }
+
+    // 大文字と小文字を区別しない
+    #[test]
+    fn case_insensitive_test() {
+        let table = [("Exit", 1)];
+        let parser = keyword_table(&table, KeywordOptions::default());
+        assert!(parser("exit").1.is_err());
+
+        let options = KeywordOptions {
+            case_insensitive: true,
+            ..Default::default()
+        };
+        let parser = keyword_table(&table, options);
+        assert_eq!(parser("eXiT"), ("", Ok(1)));
+
+        // 長さの違うキーワードを、続けて検索する
+        let table = [("Exit", 1), ("ExitAll", 2), ("ÉTÉ", 3)];
+        let parser = keyword_table(&table, options);
+        assert_eq!(parser("EXITALL now"), (" now", Ok(2)));
+        assert_eq!(parser("exit now"), (" now", Ok(1)));
+        assert_eq!(parser("été"), ("", Ok(3)));
+    }
+
+    // マルチバイト文字のキーワード
+    #[test]
+    fn multibyte_test() {
+        let table = [("終了", 1), ("終了する", 2)];
+        let parser = keyword_table(&table, KeywordOptions::default());
+        assert_eq!(parser("終了する"), ("", Ok(2)));
+        assert_eq!(parser("終了 "), (" ", Ok(1)));
+    }
+
+    // 一度作成したパーサーを、別々の入力に使い回せる
+    #[test]
+    fn reuse_test() {
+        let table = [("say", 1), ("exit", 2)];
+        let parser = keyword_table(&table, KeywordOptions::default());
+        for (line, expected) in [("exit", 2), ("say hi", 1)] {
+            let line = line.to_string();
+            assert_eq!(parser(&line).1, Ok(expected));
+        }
+    }
+}
@@ -7,14 +7,35 @@ use super::*;
 mod num;
 pub use num::*;
 
+mod int;
+pub use int::*;
+
+mod float;
+pub use float::*;
+
 mod char;
 pub use char::*;
 
 mod string;
 pub use string::*;
 
+mod satisfy;
+pub use satisfy::*;
+
+mod take_while;
+pub use take_while::*;
+
 mod trimer;
 pub use trimer::*;
 
 mod none;
 pub use none::*;
+
+mod keyword;
+pub use keyword::*;
+
+// 入力の先頭からn文字分の範囲を求める
+// エラー時に、見つかった文字を指すために使用する
+pub(crate) fn chars_span(i: &str, n: usize) -> Span {
+    token_parser::elements_span(&i, n)
+}
@@ -25,7 +25,7 @@
 /// assert_eq!(result, SubResult::A(123));
 /// assert_eq!(rest, "abc");
 /// ```
-pub fn none<'a>(i: &'a str) -> (&'a str, ()) {
+pub fn none(i: &str) -> (&str, ()) {
     (i, ())
 }
 
@@ -5,6 +5,7 @@ use super::*;
 ///
 /// - `0-9`の範囲でパースを行います。  
 ///   - `+`,`-`,`.`などの数学記号には対応しません。  
+///   - 符号や他の整数型を扱う場合は、[int][super::int]を使ってください。
 /// - 先頭から解析を行います。  
 ///
 /// # 成功時
@@ -13,6 +14,8 @@ use super::*;
 /// - 解析できなかった場合、[エラー][super::error::Error]が返されます。
 ///   - [kind][super::error::Error::kind]は [ParseNumError][super::ErrorKind::ParseNumError] になります。
 ///   - [source][std::error::Error::source]は[`parse<u64>`][`str::parse<u64>`()] のエラーである [ParseIntError][std::num::ParseIntError] になります。
+///   - [span][super::error::Error::span]は、数字が無い場合は見つかった文字を、桁あふれの場合は数字の部分を指します。
+///   - [expected][super::error::Error::expected]には、`number`が記録されます。
 ///
 /// # Example
 /// ```rust
@@ -24,11 +27,18 @@ use super::*;
 /// ```
 ///
 ///
-pub fn num<'a>(i: &'a str) -> (&'a str, Result<u64, Error>) {
+pub fn num(i: &str) -> (&str, Result<u64, Error>) {
     let l = i.find(|c: char| !c.is_ascii_digit()).unwrap_or(i.len());
     match i[..l].parse::<u64>() {
         Ok(n) => (&i[l..], Ok(n)),
-        Err(e) => (i, Err(Error::new(ErrorKind::ParseNumError(e)))),
+        Err(e) => {
+            let span = match l {
+                0 => chars_span(i, 1),
+                _ => Span::new(&i, &&i[l..]),
+            };
+            let err = Error::new(ErrorKind::ParseNumError(e), span);
+            (i, Err(err.with_expected("number")))
+        }
     }
 }
 
@@ -61,6 +71,15 @@ mod tests {
         ));
     }
 
+    // 異常系: 桁あふれした場合は数字の部分を指す
+    #[test]
+    fn overflow_span_test() {
+        let src = "x=99999999999999999999;";
+        let (rest, result) = num(&src[2..]);
+        assert_eq!(rest, &src[2..]);
+        assert_eq!(result.unwrap_err().span().range(&src), 2..22);
+    }
+
     #[test]
     // 数値のみの文字列からパースする
     fn num_only() {
@@ -82,14 +101,14 @@ mod tests {
             base.clear();
             // 該当文字と\nを加え、文字列を作成する
             base.push(char::from(b));
-            base.push_str("\n");
+            base.push('\n');
             // パースして、結果を確認する。
             let (_, result) = num(&base);
-            if b >= 0x30 && b < 0x3a {
+            if (0x30..0x3a).contains(&b) {
                 // unwrapは失敗したらpanic!を起こすので、assert同然に使用可能
                 result_list.push(result.unwrap());
             } else {
-                assert!(matches!(result, Err(_)));
+                assert!(result.is_err());
             }
         });
         println!("{:?}", result_list);
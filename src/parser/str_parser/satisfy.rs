@@ -0,0 +1,135 @@
+use super::*;
+
+/// 条件を満たす文字でパースする
+///
+/// 引数に入れた関数を条件に、先頭の1文字を解析する [Parser][super::Parser] を**作成**します。
+///
+/// # パーサーの仕様について
+/// - 先頭のみ解析を行います。
+/// ## 成功時
+/// - 条件を満たした文字が、結果として返されます。
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseCharError][super::ErrorKind::ParseCharError] になります。
+///   - [span][super::error::Error::span]は、見つかった文字を指します。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::str_parser::satisfy;
+///
+/// let parser = satisfy(|c| c.is_alphabetic());
+/// assert_eq!(parser("あいう"), ("いう", Ok('あ')));
+/// assert!(parser("123").1.is_err());
+/// ```
+pub fn satisfy<'a>(f: impl Fn(char) -> bool + Clone) -> impl Parser<&'a str, Result<char, Error>> {
+    token_parser::satisfy_or(f, |span| {
+        Error::new(ErrorKind::ParseCharError, span).with_expected("character")
+    })
+}
+
+/// 指定した文字のどれかでパースする
+///
+/// 引数に入れた文字列に含まれる、いずれかの文字を条件に解析する [Parser][super::Parser] を**作成**します。
+///
+/// # パーサーの仕様について
+/// - 先頭のみ解析を行います。
+/// ## 成功時
+/// - 見つかった文字が、結果として返されます。
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseCharError][super::ErrorKind::ParseCharError] になります。
+///   - [expected][super::error::Error::expected]には、指定した全ての文字が記録されます。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::str_parser::one_of;
+///
+/// let parser = one_of("+-*/");
+/// assert_eq!(parser("*2"), ("2", Ok('*')));
+///
+/// let (_, result) = parser("%2");
+/// assert_eq!(result.unwrap_err().expected(), ["'+'", "'-'", "'*'", "'/'"]);
+/// ```
+pub fn one_of<'a>(chars: &str) -> impl Parser<&'a str, Result<char, Error>> {
+    let chars = chars.to_string();
+    let expected = chars.clone();
+    token_parser::satisfy_or(
+        move |c| chars.contains(c),
+        move |span| {
+            expected
+                .chars()
+                .fold(Error::new(ErrorKind::ParseCharError, span), |err, c| {
+                    err.with_expected(format!("'{}'", c.escape_debug()))
+                })
+        },
+    )
+}
+
+/// 指定した文字以外でパースする
+///
+/// 引数に入れた文字列に含まれない、任意の1文字を解析する [Parser][super::Parser] を**作成**します。
+///
+/// # パーサーの仕様について
+/// - 先頭のみ解析を行います。
+/// - 入力が空の場合は失敗します。
+/// ## 成功時
+/// - 見つかった文字が、結果として返されます。
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseCharError][super::ErrorKind::ParseCharError] になります。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::str_parser::none_of;
+///
+/// let parser = none_of("\"\\");
+/// assert_eq!(parser("a\""), ("\"", Ok('a')));
+/// assert!(parser("\"").1.is_err());
+/// ```
+pub fn none_of<'a>(chars: &str) -> impl Parser<&'a str, Result<char, Error>> {
+    let chars = chars.to_string();
+    let label = format!("any character except {:?}", chars);
+    token_parser::satisfy_or(
+        move |c| !chars.contains(c),
+        move |span| Error::new(ErrorKind::ParseCharError, span).with_expected(&label),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // マルチバイト文字を条件で判定する
+    #[test]
+    fn satisfy_test() {
+        let base = "漢字かな";
+        let parser = satisfy(|c| ('\u{4E00}'..='\u{9FFF}').contains(&c));
+        let (rest, result) = parser(base);
+        assert_eq!(result, Ok('漢'));
+        let (rest, result) = parser(rest);
+        assert_eq!(result, Ok('字'));
+        let (rest, result) = parser(rest);
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::ParseCharError);
+        assert_eq!(rest, "かな");
+    }
+
+    // 指定した文字のどれか
+    #[test]
+    fn one_of_test() {
+        let parser = one_of("＋－");
+        assert_eq!(parser("－1"), ("1", Ok('－')));
+        let (rest, result) = parser("1");
+        assert_eq!(result.unwrap_err().expected(), ["'＋'", "'－'"]);
+        assert_eq!(rest, "1");
+    }
+
+    // 指定した文字以外
+    #[test]
+    fn none_of_test() {
+        let parser = none_of(",;");
+        assert_eq!(parser("é,"), (",", Ok('é')));
+        assert!(parser(";").1.is_err());
+        // 入力が空
+        assert!(parser("").1.is_err());
+    }
+}
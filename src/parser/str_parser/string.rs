@@ -11,6 +11,8 @@ use super::*;
 /// ## エラー時
 /// - [エラー][super::error::Error]が返却されます。
 ///   - [kind][super::error::Error::kind]が [ParseStringError][super::ErrorKind::ParseStringError] になります。
+///   - [span][super::error::Error::span]は、条件の文字列と同じ文字数分の入力を指します。
+///   - [expected][super::error::Error::expected]には、`'文字列'`が記録されます。
 ///
 /// # Example
 /// ```rust
@@ -22,9 +24,13 @@ use super::*;
 /// assert_eq!(rest, "_world");
 /// ```
 pub fn string<'a>(s: String) -> impl Parser<&'a str, Result<String, Error>> {
-    move |i: &'a str| match i.strip_prefix(&s) {
-        Some(i) => (i, Ok(s.clone())),
-        None => (i, Err(Error::new(ErrorKind::ParseStringError))),
+    let label = format!("'{}'", s.escape_debug());
+    let parser = token_parser::tokens_or(s.chars().collect(), move |span| {
+        Error::new(ErrorKind::ParseStringError, span).with_expected(&label)
+    });
+    move |i: &'a str| {
+        let (rest, result) = parser(i);
+        (rest, result.map(|_| s.clone()))
     }
 }
 
@@ -56,4 +62,14 @@ mod tests {
         // パースした文字列（エラー）
         assert_eq!(result.unwrap_err().kind(), &ErrorKind::ParseStringError);
     }
+
+    // 異常系: 失敗した位置を記録する
+    #[test]
+    fn error_span_test() {
+        let src = "say sax";
+        let parser = string("say".to_string());
+        let (_, result) = parser(&src[4..]);
+        // "sax"を指している
+        assert_eq!(result.unwrap_err().span().range(&src), 4..7);
+    }
 }
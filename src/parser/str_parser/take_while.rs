@@ -0,0 +1,145 @@
+use super::*;
+
+/// 条件を満たす文字が続く限りパースする
+///
+/// 引数に入れた関数を条件に、先頭から文字列を切り出す [Parser][super::Parser] を**作成**します。
+///
+/// # パーサーの仕様について
+/// - 先頭から解析を行います。
+/// - 文字単位で判定するため、マルチバイト文字の途中で切れることはありません。
+/// ## 成功時
+/// - 条件を満たした部分が、結果として返されます。
+/// - 一文字も満たさなかった場合は、空文字列が返されます。このパーサーはエラーを返すことはありません。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::str_parser::take_while;
+///
+/// let parser = take_while(|c| c.is_alphabetic());
+/// assert_eq!(parser("ひらがな123"), ("123", Ok("ひらがな")));
+/// assert_eq!(parser("123"), ("123", Ok("")));
+/// ```
+pub fn take_while<'a>(
+    f: impl Fn(char) -> bool + Clone,
+) -> impl Parser<&'a str, Result<&'a str, Error>> {
+    token_parser::take_while(f)
+}
+
+/// 条件を満たす文字が1文字以上続く限りパースする
+///
+/// [take_while]と同じですが、一文字も満たさなかった場合はエラーになります。
+///
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseTakeWhileError][super::ErrorKind::ParseTakeWhileError] になります。
+///   - [span][super::error::Error::span]は、見つかった文字を指します。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::str_parser::take_while1;
+///
+/// let parser = take_while1(|c| c.is_ascii_hexdigit());
+/// assert_eq!(parser("ff;"), (";", Ok("ff")));
+/// assert!(parser(";").1.is_err());
+/// ```
+pub fn take_while1<'a>(
+    f: impl Fn(char) -> bool + Clone,
+) -> impl Parser<&'a str, Result<&'a str, Error>> {
+    take_while_m_n(1, usize::MAX, f)
+}
+
+/// 条件を満たす文字が、m文字以上n文字以下続く部分をパースする
+///
+/// 最大でn文字まで切り出します。文字数はバイト数ではなく、文字の数で数えます。
+///
+/// ## エラー時
+/// - m文字に満たなかった場合、[エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseTakeWhileError][super::ErrorKind::ParseTakeWhileError] になります。
+///   - [span][super::error::Error::span]は、条件を満たさなかった文字を指します。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::str_parser::take_while_m_n;
+///
+/// // 2〜4桁の数字
+/// let parser = take_while_m_n(2, 4, |c| c.is_ascii_digit());
+/// assert_eq!(parser("123456"), ("56", Ok("1234")));
+/// assert_eq!(parser("12a"), ("a", Ok("12")));
+/// assert!(parser("1a").1.is_err());
+/// ```
+pub fn take_while_m_n<'a>(
+    m: usize,
+    n: usize,
+    f: impl Fn(char) -> bool + Clone,
+) -> impl Parser<&'a str, Result<&'a str, Error>> {
+    let parser = token_parser::take_while_m_n(m, n, f);
+    move |i: &'a str| {
+        let (rest, result) = parser(i);
+        (rest, result.map_err(|e| e.with_expected("character")))
+    }
+}
+
+/// 条件を満たす文字が現れるまでパースする
+///
+/// [take_while]の条件を反転したものです。条件を満たす文字は消費されません。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::str_parser::take_till;
+///
+/// let parser = take_till(|c| c == '、');
+/// assert_eq!(parser("こんにちは、世界"), ("、世界", Ok("こんにちは")));
+/// ```
+pub fn take_till<'a>(
+    f: impl Fn(char) -> bool + Clone,
+) -> impl Parser<&'a str, Result<&'a str, Error>> {
+    take_while(move |c| !f(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // マルチバイト文字の途中で切れない
+    #[test]
+    fn take_while_multibyte_test() {
+        let parser = take_while(|c| c != '=');
+        let (rest, result) = parser("ｋｅｙ=値");
+        assert_eq!(result, Ok("ｋｅｙ"));
+        assert_eq!(rest, "=値");
+    }
+
+    // 1文字以上必要
+    #[test]
+    fn take_while1_failure_test() {
+        let src = "ab=";
+        let parser = take_while1(|c| c.is_ascii_digit());
+        let (rest, result) = parser(src);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::ParseTakeWhileError);
+        assert_eq!(err.span().range(&src), 0..1);
+        assert_eq!(rest, "ab=");
+    }
+
+    // 文字数で数える
+    #[test]
+    fn take_while_m_n_chars_test() {
+        let parser = take_while_m_n(1, 2, |c| c.is_alphabetic());
+        assert_eq!(parser("あいう"), ("う", Ok("あい")));
+        let src = "x1";
+        let parser = take_while_m_n(2, 3, |c| c.is_alphabetic());
+        let (rest, result) = parser(src);
+        // 条件を満たさなかった'1'を指す
+        assert_eq!(result.unwrap_err().span().range(&src), 1..2);
+        assert_eq!(rest, "x1");
+    }
+
+    // 条件を満たす文字が現れるまで
+    #[test]
+    fn take_till_test() {
+        let parser = take_till(|c| c == ';');
+        assert_eq!(parser("abc;def"), (";def", Ok("abc")));
+        assert_eq!(parser("abc"), ("", Ok("abc")));
+        assert_eq!(parser(";"), (";", Ok("")));
+    }
+}
@@ -12,7 +12,7 @@
 /// let (rest, _) = trimer(input);
 /// assert_eq!(rest, "abc");
 /// ```
-pub fn trimer<'a>(i: &'a str) -> (&'a str, ()) {
+pub fn trimer(i: &str) -> (&str, ()) {
     (i.trim_start(), ())
 }
 
@@ -0,0 +1,36 @@
+//! 任意の入力で使える、汎用的なパーサーを提供するモジュール
+//!
+//! このモジュールのパーサーは、[Input]を実装した入力であれば何でも扱えます。  
+//! 文字列でもバイト列でも、字句解析済みのトークン列でも、同じ文法の定義をそのまま使えます。  
+//! [str_parser]や[bytes_parser]の一部のパーサーは、このモジュールを元に作られています。
+use super::*;
+
+mod token;
+pub use token::*;
+
+mod take_while;
+pub use take_while::*;
+
+// 入力の先頭からn要素分の範囲を求める
+// 入力が足りない場合は、入力の終わりまでを指す
+pub(crate) fn elements_span<I: Input>(i: &I, n: usize) -> Span {
+    let len = i.iter_elements().nth(n).map_or(i.input_len(), |(p, _)| p);
+    Span::new(i, &i.split_at_offset(len).1)
+}
+
+// 先頭から、条件を満たす要素が続く範囲を求める
+// 最大max要素まで読み、(要素数, オフセット)を返す
+pub(crate) fn scan_while<I: Input>(
+    i: &I,
+    max: usize,
+    f: impl Fn(I::Item) -> bool,
+) -> (usize, usize) {
+    let mut count = 0;
+    for (p, x) in i.iter_elements() {
+        if count == max || !f(x) {
+            return (count, p);
+        }
+        count += 1;
+    }
+    (count, i.input_len())
+}
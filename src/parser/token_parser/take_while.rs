@@ -0,0 +1,103 @@
+use super::*;
+
+/// 条件を満たす要素が続く限りパースする
+///
+/// 引数に入れた関数を条件に、先頭から入力を切り出す [Parser][super::Parser] を**作成**します。  
+/// [str_parser::take_while][crate::parser::str_parser::take_while]を、任意の入力で使えるようにしたものです。
+///
+/// ## 成功時
+/// - 条件を満たした部分が、結果として返されます。
+/// - 一つも満たさなかった場合は、空の入力が返されます。このパーサーはエラーを返すことはありません。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::token_parser::take_while;
+///
+/// let input: &[i32] = &[2, 4, 5, 6];
+/// let (rest, result) = take_while(|n: i32| n % 2 == 0)(input);
+/// assert_eq!(result, Ok(&input[..2]));
+/// assert_eq!(rest, &[5, 6]);
+/// ```
+pub fn take_while<I: Input>(
+    f: impl Fn(I::Item) -> bool + Clone,
+) -> impl Parser<I, Result<I, Error>> {
+    move |i: I| {
+        let (_, len) = scan_while(&i, usize::MAX, &f);
+        let (head, rest) = i.split_at_offset(len);
+        (rest, Ok(head))
+    }
+}
+
+/// 条件を満たす要素が1つ以上続く限りパースする
+///
+/// [take_while]と同じですが、一つも満たさなかった場合はエラーになります。
+///
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseTakeWhileError][super::ErrorKind::ParseTakeWhileError] になります。
+///   - [span][super::error::Error::span]は、見つかった要素を指します。
+pub fn take_while1<I: Input>(
+    f: impl Fn(I::Item) -> bool + Clone,
+) -> impl Parser<I, Result<I, Error>> {
+    take_while_m_n(1, usize::MAX, f)
+}
+
+/// 条件を満たす要素が、m個以上n個以下続く部分をパースする
+///
+/// 最大でn個まで切り出します。
+///
+/// ## エラー時
+/// - m個に満たなかった場合、[エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseTakeWhileError][super::ErrorKind::ParseTakeWhileError] になります。
+///   - [span][super::error::Error::span]は、条件を満たさなかった要素を指します。
+pub fn take_while_m_n<I: Input>(
+    m: usize,
+    n: usize,
+    f: impl Fn(I::Item) -> bool + Clone,
+) -> impl Parser<I, Result<I, Error>> {
+    move |i: I| {
+        let (count, len) = scan_while(&i, n, &f);
+        let (head, rest) = i.split_at_offset(len);
+        if count < m {
+            let err = Error::new(ErrorKind::ParseTakeWhileError, elements_span(&rest, 1));
+            return (i, Err(err));
+        }
+        (rest, Ok(head))
+    }
+}
+
+/// 条件を満たす要素が現れるまでパースする
+///
+/// [take_while]の条件を反転したものです。条件を満たす要素は消費されません。
+pub fn take_till<I: Input>(
+    f: impl Fn(I::Item) -> bool + Clone,
+) -> impl Parser<I, Result<I, Error>> {
+    take_while(move |x| !f(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 個数で数える
+    #[test]
+    fn take_while_m_n_test() {
+        let base: &[char] = &['a', 'b', 'c', '1'];
+        let parser = take_while_m_n(1, 2, |c: char| c.is_alphabetic());
+        assert_eq!(parser(base), (&base[2..], Ok(&base[..2])));
+        let parser = take_while_m_n(4, 5, |c: char| c.is_alphabetic());
+        let (rest, result) = parser(base);
+        // 条件を満たさなかった'1'を指す
+        assert_eq!(result.unwrap_err().span().range(&base), 3..4);
+        assert_eq!(rest, base);
+    }
+
+    // 条件を満たす要素が現れるまで
+    #[test]
+    fn take_till_test() {
+        let base: &[u8] = b"key=value";
+        let (rest, result) = take_till(|b| b == b'=')(base);
+        assert_eq!(result, Ok(&b"key"[..]));
+        assert_eq!(rest, b"=value");
+    }
+}
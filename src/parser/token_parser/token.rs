@@ -0,0 +1,213 @@
+use super::*;
+
+// 先頭の1要素を条件で判定するパーサーを作成する
+// 失敗した時は、見つかった要素の範囲からerrでエラーを作成する
+pub(crate) fn satisfy_or<I: Input>(
+    f: impl Fn(I::Item) -> bool + Clone,
+    err: impl Fn(Span) -> Error + Clone,
+) -> impl Parser<I, Result<I::Item, Error>> {
+    move |i: I| {
+        // 条件を満たした場合は、(要素, 次の要素のオフセット)を求める
+        let found = {
+            let mut elements = i.iter_elements();
+            match elements.next() {
+                Some((_, x)) if f(x.clone()) => {
+                    Some((x, elements.next().map_or(i.input_len(), |(p, _)| p)))
+                }
+                _ => None,
+            }
+        };
+        match found {
+            Some((x, len)) => (i.split_at_offset(len).1, Ok(x)),
+            None => {
+                let span = elements_span(&i, 1);
+                (i, Err(err(span)))
+            }
+        }
+    }
+}
+
+// 先頭から要素の並びが一致するか判定するパーサーを作成する
+// 成功時は一致した部分の入力を返す
+// 失敗した時は、並びと同じ要素数分の範囲からerrでエラーを作成する
+pub(crate) fn tokens_or<I: Input>(
+    seq: Vec<I::Item>,
+    err: impl Fn(Span) -> Error + Clone,
+) -> impl Parser<I, Result<I, Error>>
+where
+    I::Item: PartialEq,
+{
+    move |i: I| {
+        // 一致した場合は、一致した部分のオフセットを求める
+        let found = {
+            let mut elements = i.iter_elements();
+            seq.iter()
+                .all(|x| elements.next().is_some_and(|(_, y)| *x == y))
+                .then(|| elements.next().map_or(i.input_len(), |(p, _)| p))
+        };
+        match found {
+            Some(len) => {
+                let (head, rest) = i.split_at_offset(len);
+                (rest, Ok(head))
+            }
+            None => {
+                let span = elements_span(&i, seq.len());
+                (i, Err(err(span)))
+            }
+        }
+    }
+}
+
+/// 条件を満たす要素でパースする
+///
+/// 引数に入れた関数を条件に、先頭の1要素を解析する [Parser][super::Parser] を**作成**します。  
+/// [str_parser::satisfy][crate::parser::str_parser::satisfy]を、任意の入力で使えるようにしたものです。
+///
+/// ## 成功時
+/// - 条件を満たした要素が、結果として返されます。
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseTokenError][super::ErrorKind::ParseTokenError] になります。
+///   - [span][super::error::Error::span]は、見つかった要素を指します。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::token_parser::satisfy;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Token {
+///     Num(i64),
+///     Plus,
+/// }
+///
+/// let input: &[Token] = &[Token::Num(1), Token::Plus, Token::Num(2)];
+/// let parser = satisfy(|t| matches!(t, Token::Num(_)));
+/// let (rest, result) = parser(input);
+/// assert_eq!(result, Ok(Token::Num(1)));
+/// assert_eq!(rest, &[Token::Plus, Token::Num(2)]);
+/// ```
+pub fn satisfy<I: Input>(
+    f: impl Fn(I::Item) -> bool + Clone,
+) -> impl Parser<I, Result<I::Item, Error>> {
+    satisfy_or(f, |span| Error::new(ErrorKind::ParseTokenError, span))
+}
+
+/// 要素でパースする
+///
+/// 引数に入れた要素を条件に解析する [Parser][super::Parser] を**作成**します。  
+/// [str_parser::char][crate::parser::str_parser::char]を、任意の入力で使えるようにしたものです。
+///
+/// ## 成功時
+/// - 条件の要素が解析できたら、その要素が結果として返されます。
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseTokenError][super::ErrorKind::ParseTokenError] になります。
+///   - [expected][super::error::Error::expected]には、要素を[Debug]で表示したものが記録されます。
+///
+/// # Example
+/// ```rust
+/// use my_parser_project::parser::token_parser::token;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Token {
+///     Num(i64),
+///     Plus,
+/// }
+///
+/// let input: &[Token] = &[Token::Plus, Token::Num(2)];
+/// assert_eq!(token(Token::Plus)(input), (&input[1..], Ok(Token::Plus)));
+///
+/// let (_, result) = token(Token::Plus)(&input[1..]);
+/// assert_eq!(result.unwrap_err().expected(), ["Plus"]);
+/// ```
+pub fn token<I: Input>(t: I::Item) -> impl Parser<I, Result<I::Item, Error>>
+where
+    I::Item: PartialEq + std::fmt::Debug,
+{
+    let label = format!("{:?}", t);
+    satisfy_or(
+        move |x| x == t,
+        move |span| Error::new(ErrorKind::ParseTokenError, span).with_expected(&label),
+    )
+}
+
+/// 要素の並びでパースする
+///
+/// 引数に入れた要素の並びを条件に解析する [Parser][super::Parser] を**作成**します。  
+/// [str_parser::string][crate::parser::str_parser::string]を、任意の入力で使えるようにしたものです。
+///
+/// ## 成功時
+/// - 条件の並びが解析できたら、入力のその部分が結果として返されます。
+/// ## エラー時
+/// - [エラー][super::error::Error]が返却されます。
+///   - [kind][super::error::Error::kind]が [ParseTokenError][super::ErrorKind::ParseTokenError] になります。
+///   - [span][super::error::Error::span]は、条件の並びと同じ要素数分の入力を指します。
+///
+/// # Example
+/// 同じ文法の定義を、文字列と文字のスライスの両方で使えます。
+/// ```rust
+/// use my_parser_project::parser::{token_parser::tokens, Input, Parser, Error};
+///
+/// fn hello<I: Input<Item = char>>() -> impl Parser<I, Result<I, Error>> {
+///     tokens(&['h', 'i'])
+/// }
+///
+/// assert_eq!(hello()("hi!"), ("!", Ok("hi")));
+///
+/// let chars: &[char] = &['h', 'i', '!'];
+/// assert_eq!(hello()(chars), (&chars[2..], Ok(&chars[..2])));
+/// ```
+pub fn tokens<I: Input>(seq: &[I::Item]) -> impl Parser<I, Result<I, Error>>
+where
+    I::Item: PartialEq + std::fmt::Debug,
+{
+    let label = format!("{:?}", seq);
+    tokens_or(seq.to_vec(), move |span| {
+        Error::new(ErrorKind::ParseTokenError, span).with_expected(&label)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Token {
+        Ident(String),
+        LParen,
+        RParen,
+    }
+
+    // トークン列をパースする
+    #[test]
+    fn token_test() {
+        let base: &[Token] = &[Token::Ident("f".to_string()), Token::LParen, Token::RParen];
+        let (rest, result) = satisfy(|t| matches!(t, Token::Ident(_)))(base);
+        assert_eq!(result, Ok(Token::Ident("f".to_string())));
+        let (rest, result) = tokens(&[Token::LParen, Token::RParen])(rest);
+        assert_eq!(result, Ok(&base[1..]));
+        assert!(rest.is_empty());
+    }
+
+    // 失敗した位置を記録する
+    #[test]
+    fn error_span_test() {
+        let base: &[Token] = &[Token::LParen, Token::LParen];
+        let (rest, result) = tokens(&[Token::LParen, Token::RParen])(&base[1..]);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::ParseTokenError);
+        // 入力が足りない場合は、入力の終わりまでを指す
+        assert_eq!(err.span().range(&base), 1..2);
+        assert_eq!(rest, &[Token::LParen]);
+    }
+
+    // 文字列では、要素は文字になる
+    #[test]
+    fn str_test() {
+        let (rest, result) = token('あ')("あい");
+        assert_eq!(result, Ok('あ'));
+        assert_eq!(rest, "い");
+        let (_, result) = token::<&str>('う')("あい");
+        assert_eq!(result.unwrap_err().span().range(&"あい"), 0..3);
+    }
+}